@@ -1,20 +1,33 @@
 use anyhow::Result;
+use crossterm::event::KeyCode;
 use std::io::{self, StdoutLock, Write};
 use std::sync::Arc;
-use tokio::sync::{Mutex, mpsc};
+use tokio::sync::{Mutex, MutexGuard, mpsc};
 
 use crate::core::{
-    GameEngine,
-    types::{GameCommand, GameResult},
+    Calendar, GameEngine,
+    animal::{AnimalKind, BuildingKind, HAY_PRICE, animal_catalog},
+    crafting::{item_sell_price, recipe_catalog},
+    machine::{find_product, machine_catalog},
+    soil::fertilizer_catalog,
+    sprinkler::sprinkler_catalog,
+    types::{GameCommand, GameResult, TimeMode},
 };
 
-use super::{input::InputEvent, persistence::GamePersistence, renderer::GameRenderer};
+use super::{
+    input::{InputEvent, pick, read_command, wait_for_key},
+    persistence::GamePersistence,
+    renderer::GameRenderer,
+    script,
+    tui::Tui,
+};
 
 #[cfg(feature = "network")]
 use crate::network::TradeManager;
 
 pub struct CliApp {
-    game_engine: GameEngine,
+    /// The only copy of the game, shared with the day monitor and the trade node
+    game_engine: Arc<Mutex<GameEngine>>,
     #[cfg(feature = "network")]
     trade_manager: Option<TradeManager>,
 }
@@ -22,20 +35,20 @@ pub struct CliApp {
 impl CliApp {
     pub fn new(game_engine: GameEngine) -> Self {
         Self {
-            game_engine,
+            game_engine: Arc::new(Mutex::new(game_engine)),
             #[cfg(feature = "network")]
             trade_manager: None,
         }
     }
 
-    pub fn load_or_create() -> Result<Self> {
-        let game_engine = match GamePersistence::load()? {
+    /// Load the saved game, or create a new one using the given calendar
+    pub fn load_or_create(calendar: Calendar) -> Result<Self> {
+        let game_engine = match GamePersistence::resume(&mut io::stdout().lock())? {
             Some(engine) => {
                 println!("📂 Loaded existing game save");
                 engine
             }
             None => {
-                println!("🌱 Welcome to the P2P Harvest Game! 🌱");
                 println!("Your Name: ");
                 io::stdout().flush()?;
 
@@ -43,15 +56,45 @@ impl CliApp {
                 io::stdin().read_line(&mut input)?;
 
                 let name = input.trim();
-                GameEngine::new_game(name)
+                GameEngine::new_game_with_calendar(name, calendar)
             }
         };
 
         Ok(Self::new(game_engine))
     }
 
+    /// Apply time settings given on the command line
+    pub fn apply_time_settings(
+        &mut self,
+        mode: Option<TimeMode>,
+        minutes_per_day: Option<i64>,
+    ) -> Result<()> {
+        if mode.is_none() && minutes_per_day.is_none() {
+            return Ok(());
+        }
+
+        let mode = mode.unwrap_or(self.engine().get_time_config().mode);
+        let result = self.engine().execute(GameCommand::SetTimeMode {
+            mode,
+            minutes_per_day,
+        });
+
+        let mut stdout = io::stdout().lock();
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(&mut stdout, &event)?;
+                self.save()?;
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed to change time mode: {}\r\n", err)?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn save(&self) -> Result<()> {
-        GamePersistence::save(&self.game_engine)
+        GamePersistence::save(&self.engine())
     }
 
     pub fn reset() -> Result<()> {
@@ -80,11 +123,10 @@ impl CliApp {
             self.initialize_networking()?;
         }
 
-        let game_engine_arc = Arc::new(Mutex::new(self.game_engine.clone()));
         let (tx, mut rx) = mpsc::unbounded_channel();
 
         // Spawn day change monitor
-        let game_engine_clone = game_engine_arc.clone();
+        let game_engine_clone = self.game_engine.clone();
         std::thread::spawn(move || {
             let rt = tokio::runtime::Runtime::new().unwrap();
             rt.block_on(async {
@@ -95,67 +137,129 @@ impl CliApp {
         let mut stdout = io::stdout().lock();
 
         loop {
-            // Check for auto day changes
-            if let Ok(notification) = rx.try_recv() {
-                write!(stdout, "\r\n")?;
-                GameRenderer::render_event(&mut stdout, &notification)?;
-                write!(stdout, "\r\n")?;
-                stdout.flush()?;
-
-                // Sync game engine
-                let rt = tokio::runtime::Runtime::new()?;
-                self.game_engine = rt.block_on(async { game_engine_arc.lock().await.clone() });
-            }
-
             // Display menu
             write!(stdout, "Control Instructions:\r\n")?;
             write!(
                 stdout,
-                "🎮 plant/water/harvest/sleep/status/trade/quit 🎮\r\n"
+                "🎮 [p]lant [w]ater [h]arvest [g]ather sel[l] [b]uy c[r]aft [f]ertilize sprin[k]ler [m]achine [a]nimals [s]leep stat[i]s [c]ontest [t]rade sett[o]ings [q]uit · [:] command (:help) 🎮\r\n"
             )?;
+            stdout.flush()?;
 
-            // Read input
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)?;
+            // Wait for a hotkey, showing automatic day changes as they happen
+            let key = wait_for_key(|| {
+                if let Ok(notification) = rx.try_recv() {
+                    write!(stdout, "\r\n")?;
+                    GameRenderer::render_event(&mut stdout, &notification)?;
+                    write!(stdout, "\r\n")?;
+                    stdout.flush()?;
+                }
+                Ok(())
+            })?;
 
-            let cmd = input.trim();
-            if let Some(input_event) = InputEvent::from_str(cmd) {
-                write!(stdout, "\r\n")?;
+            let input_event = if key.code == KeyCode::Char(':') {
+                let crops: Vec<String> = self
+                    .engine()
+                    .get_info()
+                    .inventory
+                    .iter()
+                    .map(|crop| crop.name.clone())
+                    .collect();
 
-                match input_event {
-                    InputEvent::Quit => {
-                        write!(stdout, "👋 Thanks for playing. Goodbye!\r\n")?;
-                        self.save()?;
-                        break;
-                    }
-                    InputEvent::Sleep => {
-                        self.handle_sleep(&mut stdout, &game_engine_arc)?;
-                    }
-                    InputEvent::PlantCrop => {
-                        self.handle_plant_crop(&mut stdout)?;
-                    }
-                    InputEvent::WaterCrops => {
-                        self.handle_water_crops(&mut stdout)?;
-                    }
-                    InputEvent::HarvestCrops => {
-                        self.handle_harvest_crops(&mut stdout)?;
-                    }
-                    InputEvent::Status => {
-                        self.handle_status(&mut stdout)?;
+                let Some(line) = read_command(&mut stdout, &crops)? else {
+                    continue;
+                };
+
+                // Bare command words open the interactive menus; anything with
+                // arguments goes through the command grammar
+                match InputEvent::parse(&line) {
+                    Some(input_event) => input_event,
+                    None if line == "help" => {
+                        for usage in script::USAGE {
+                            write!(stdout, "  {}\r\n", usage)?;
+                        }
+                        write!(stdout, "\r\n")?;
+                        continue;
                     }
-                    InputEvent::Trade => {
-                        #[cfg(feature = "network")]
-                        self.handle_trade(&mut stdout)?;
-
-                        #[cfg(not(feature = "network"))]
-                        write!(
-                            stdout,
-                            "❌ Trading is not available (network feature disabled)\r\n"
-                        )?;
+                    None => {
+                        let succeeded =
+                            script::execute_line(&mut self.engine(), &line, &mut stdout)?;
+                        if succeeded {
+                            self.save()?;
+                        }
+                        write!(stdout, "\r\n")?;
+                        continue;
                     }
                 }
             } else {
-                write!(stdout, "😖 Unknown command: {}\r\n", cmd)?;
+                match InputEvent::from_key(key) {
+                    Some(input_event) => input_event,
+                    None => continue,
+                }
+            };
+
+            write!(stdout, "\r\n")?;
+
+            match input_event {
+                InputEvent::Quit => {
+                    write!(stdout, "👋 Thanks for playing. Goodbye!\r\n")?;
+                    self.save()?;
+                    break;
+                }
+                InputEvent::Sleep => {
+                    self.handle_sleep(&mut stdout)?;
+                }
+                InputEvent::PlantCrop => {
+                    self.handle_plant_crop(&mut stdout)?;
+                }
+                InputEvent::WaterCrops => {
+                    self.handle_water_crops(&mut stdout)?;
+                }
+                InputEvent::HarvestCrops => {
+                    self.handle_harvest_crops(&mut stdout)?;
+                }
+                InputEvent::GatherCrops => {
+                    self.handle_gather_crops(&mut stdout)?;
+                }
+                InputEvent::SellItem => {
+                    self.handle_sell_item(&mut stdout)?;
+                }
+                InputEvent::Shop => {
+                    self.handle_shop(&mut stdout)?;
+                }
+                InputEvent::Craft => {
+                    self.handle_craft(&mut stdout)?;
+                }
+                InputEvent::Status => {
+                    self.handle_status(&mut stdout)?;
+                }
+                InputEvent::Contest => {
+                    self.handle_crop_contest(&mut stdout)?;
+                }
+                InputEvent::Fertilize => {
+                    self.handle_fertilize(&mut stdout)?;
+                }
+                InputEvent::Sprinkler => {
+                    self.handle_sprinkler(&mut stdout)?;
+                }
+                InputEvent::Machine => {
+                    self.handle_machine(&mut stdout)?;
+                }
+                InputEvent::Animals => {
+                    self.handle_animals(&mut stdout)?;
+                }
+                InputEvent::Settings => {
+                    self.handle_settings(&mut stdout)?;
+                }
+                InputEvent::Trade => {
+                    #[cfg(feature = "network")]
+                    self.handle_trade(&mut stdout)?;
+
+                    #[cfg(not(feature = "network"))]
+                    write!(
+                        stdout,
+                        "❌ Trading is not available (network feature disabled)\r\n"
+                    )?;
+                }
             }
 
             write!(stdout, "\r\n")?;
@@ -165,79 +269,359 @@ impl CliApp {
         Ok(())
     }
 
-    fn handle_sleep(
+    /// Run the full-screen terminal UI instead of the line-based menu
+    pub fn run_tui(&mut self) -> Result<()> {
+        #[cfg(feature = "network")]
+        {
+            self.initialize_networking()?;
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let game_engine_clone = self.game_engine.clone();
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(async {
+                monitor_day_changes(game_engine_clone, tx).await;
+            });
+        });
+
+        #[cfg(feature = "network")]
+        let (trade_events, endpoint_id) = match &self.trade_manager {
+            Some(trade_manager) => {
+                let (trade_tx, trade_rx) = mpsc::unbounded_channel();
+                trade_manager.watch_trades(trade_tx)?;
+                (
+                    Some(trade_rx),
+                    trade_manager.get_endpoint_id().map(|id| id.to_string()),
+                )
+            }
+            None => (None, None),
+        };
+        #[cfg(not(feature = "network"))]
+        let (trade_events, endpoint_id) = (None, None);
+
+        Tui::new(self.game_engine.clone(), rx, trade_events, endpoint_id).run()?;
+
+        self.save()?;
+        println!("👋 Thanks for playing. Goodbye!");
+
+        Ok(())
+    }
+
+    /// Lock the game for a command. Don't hold the guard while waiting for input,
+    /// or day changes and trades wait too.
+    fn engine(&self) -> MutexGuard<'_, GameEngine> {
+        self.game_engine.blocking_lock()
+    }
+
+    fn handle_sleep(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let result = self.engine().execute(GameCommand::Sleep);
+
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(stdout, &event)?;
+                self.save()?;
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Error: {}\r\n", err)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_plant_crop(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let info = self.engine().get_info();
+        let player = self.engine().get_player().clone();
+
+        if info.inventory.is_empty() {
+            write!(stdout, "😖 You don't have any seeds.\r\n")?;
+            return Ok(());
+        }
+
+        let options: Vec<String> = info
+            .inventory
+            .iter()
+            .map(|crop| {
+                format!(
+                    "{} {} (Growth Days: {}, Sell Price: {}, Energy Cost: {})",
+                    crop.icon,
+                    crop.name,
+                    crop.growth_days,
+                    crop.sell_price,
+                    player.energy_cost(crop)
+                )
+            })
+            .collect();
+
+        write!(stdout, "🌱 Choose a crop to plant:\r\n")?;
+        let Some(crop_index) = pick(stdout, &options)? else {
+            write!(stdout, "❌ Planting cancelled.\r\n")?;
+            return Ok(());
+        };
+
+        GameRenderer::render_farm(stdout, &info)?;
+        write!(
+            stdout,
+            "🗺️  Select a plot by number (press Enter for the first free plot):\r\n"
+        )?;
+        let mut plot_input = String::new();
+        io::stdin().read_line(&mut plot_input)?;
+
+        let plot = match plot_input.trim() {
+            "" => None,
+            input => match input.parse::<usize>() {
+                Ok(plot) if plot > 0 => Some(plot - 1),
+                _ => {
+                    write!(stdout, "😖 Invalid plot.\r\n")?;
+                    return Ok(());
+                }
+            },
+        };
+
+        self.plant(stdout, crop_index, plot)
+    }
+
+    fn plant(
         &mut self,
         stdout: &mut StdoutLock,
-        game_engine_arc: &Arc<Mutex<GameEngine>>,
+        crop_index: usize,
+        plot: Option<usize>,
     ) -> Result<()> {
-        let result = self.game_engine.execute(GameCommand::Sleep);
+        let result = self
+            .engine()
+            .execute(GameCommand::PlantCrop { crop_index, plot });
 
         match result {
             GameResult::Success(event) => {
                 GameRenderer::render_event(stdout, &event)?;
                 self.save()?;
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed to plant crop: {}\r\n", err)?;
+            }
+        }
 
-                // Update shared state
-                let rt = tokio::runtime::Runtime::new()?;
-                rt.block_on(async {
-                    *game_engine_arc.lock().await = self.game_engine.clone();
-                });
+        Ok(())
+    }
+
+    fn handle_water_crops(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let result = self.engine().execute(GameCommand::WaterCrops);
+
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(stdout, &event)?;
+                self.save()?;
             }
             GameResult::Error(err) => {
-                write!(stdout, "😖 Error: {}\r\n", err)?;
+                write!(stdout, "😖 Failed to water crops: {}\r\n", err)?;
             }
         }
 
         Ok(())
     }
 
-    fn handle_plant_crop(&mut self, stdout: &mut StdoutLock) -> Result<()> {
-        let info = self.game_engine.get_info();
+    fn handle_harvest_crops(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let result = self.engine().execute(GameCommand::HarvestCrops);
+
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(stdout, &event)?;
+                self.save()?;
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed to harvest crops: {}\r\n", err)?;
+            }
+        }
 
-        write!(stdout, "📦 Your inventory:\r\n")?;
-        for (index, crop) in info.inventory.iter().enumerate() {
+        Ok(())
+    }
+
+    fn handle_gather_crops(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let result = self.engine().execute(GameCommand::GatherCrops);
+
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(stdout, &event)?;
+                self.save()?;
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed to gather crops: {}\r\n", err)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_sell_item(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let sellable: Vec<(String, u32, u32)> = self
+            .engine()
+            .get_player()
+            .items
+            .iter()
+            .filter_map(|(name, count)| {
+                item_sell_price(name).map(|price| (name.clone(), *count, price))
+            })
+            .collect();
+
+        if sellable.is_empty() {
+            write!(stdout, "😖 You have nothing to sell.\r\n")?;
+            return Ok(());
+        }
+
+        for (index, (name, count, price)) in sellable.iter().enumerate() {
             write!(
                 stdout,
-                "{}. {} (Growth Days: {}, Sell Price: {}, Energy Cost: {})\r\n",
+                "{}. {} x{} ({} coins each)\r\n",
                 index + 1,
-                crop.name,
-                crop.growth_days,
-                crop.sell_price,
-                crop.energy_cost
+                name,
+                count,
+                price
             )?;
         }
 
-        write!(stdout, "🌱 Select a crop to plant by number:\r\n")?;
+        write!(stdout, "🪙 Select an item to sell by number:\r\n")?;
         let mut selected = String::new();
         io::stdin().read_line(&mut selected)?;
 
-        if let Ok(selected) = selected.trim().parse::<usize>() {
-            if selected > 0 && selected <= info.inventory.len() {
-                let result = self.game_engine.execute(GameCommand::PlantCrop {
-                    crop_index: selected - 1,
-                });
+        let (item_name, owned, _) = match selected.trim().parse::<usize>() {
+            Ok(n) if n > 0 && n <= sellable.len() => sellable[n - 1].clone(),
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        };
 
-                match result {
-                    GameResult::Success(event) => {
-                        GameRenderer::render_event(stdout, &event)?;
-                        self.save()?;
-                    }
-                    GameResult::Error(err) => {
-                        write!(stdout, "😖 Failed to plant crop: {}\r\n", err)?;
-                    }
+        write!(
+            stdout,
+            "🪙 How many? (press Enter to sell all {}):\r\n",
+            owned
+        )?;
+        let mut quantity_input = String::new();
+        io::stdin().read_line(&mut quantity_input)?;
+
+        let quantity = match quantity_input.trim() {
+            "" => owned,
+            input => match input.parse::<u32>() {
+                Ok(quantity) => quantity,
+                Err(_) => {
+                    write!(stdout, "😖 Invalid quantity.\r\n")?;
+                    return Ok(());
                 }
-            } else {
-                write!(stdout, "😖 Invalid selection.\r\n")?;
+            },
+        };
+
+        let result = self.engine().execute(GameCommand::SellItem {
+            item_name,
+            quantity,
+        });
+
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(stdout, &event)?;
+                self.save()?;
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed to sell: {}\r\n", err)?;
             }
-        } else {
-            write!(stdout, "😖 Invalid input.\r\n")?;
         }
 
         Ok(())
     }
 
-    fn handle_water_crops(&mut self, stdout: &mut StdoutLock) -> Result<()> {
-        let result = self.game_engine.execute(GameCommand::WaterCrops);
+    fn handle_shop(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let info = self.engine().get_info();
+        let seeds = self.engine().available_seeds();
+
+        write!(
+            stdout,
+            "🏪 Seed shop ({} coins to spend):\r\n",
+            info.player_money
+        )?;
+        let options: Vec<String> = seeds
+            .iter()
+            .map(|seed| {
+                format!(
+                    "{} {} (price: {}, grows in {} days, sells for {})",
+                    seed.icon,
+                    seed.name,
+                    seed.seed_price(),
+                    seed.growth_days,
+                    seed.sell_price
+                )
+            })
+            .collect();
+
+        write!(stdout, "🌱 Choose a seed to buy:\r\n")?;
+        let Some(seed_index) = pick(stdout, &options)? else {
+            write!(stdout, "❌ Shopping cancelled.\r\n")?;
+            return Ok(());
+        };
+        let seed = &seeds[seed_index];
+
+        write!(stdout, "🛒 How many {} seeds?\r\n", seed.name)?;
+        let mut quantity = String::new();
+        io::stdin().read_line(&mut quantity)?;
+
+        let Ok(quantity) = quantity.trim().parse::<u32>() else {
+            write!(stdout, "😖 Invalid quantity.\r\n")?;
+            return Ok(());
+        };
+
+        for _ in 0..quantity {
+            let result = self.engine().execute(GameCommand::BuySeed {
+                seed_name: seed.name.clone(),
+            });
+            if !self.render_result(stdout, result)? {
+                break;
+            }
+        }
+
+        self.save()?;
+        Ok(())
+    }
+
+    fn handle_craft(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let recipes = recipe_catalog();
+        let player = self.engine().get_player().clone();
+
+        write!(stdout, "🔨 Recipes:\r\n")?;
+        for (index, recipe) in recipes.iter().enumerate() {
+            let ingredients: Vec<String> = recipe
+                .ingredients
+                .iter()
+                .map(|(item, count)| format!("{} {}/{}", item, player.item_count(item), count))
+                .collect();
+
+            write!(
+                stdout,
+                "{}. {} {} ({}, {} day(s))",
+                index + 1,
+                recipe.icon,
+                recipe.name,
+                ingredients.join(", "),
+                recipe.days
+            )?;
+            if player.skills.farming_level() < recipe.level {
+                write!(stdout, " 🔒 farming level {}", recipe.level)?;
+            }
+            write!(stdout, "\r\n")?;
+        }
+
+        write!(stdout, "🔨 Select a recipe by number:\r\n")?;
+        let mut selected = String::new();
+        io::stdin().read_line(&mut selected)?;
+
+        let recipe_id = match selected.trim().parse::<usize>() {
+            Ok(n) if n > 0 && n <= recipes.len() => recipes[n - 1].id.clone(),
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        };
+
+        let result = self.engine().execute(GameCommand::Craft { recipe_id });
 
         match result {
             GameResult::Success(event) => {
@@ -245,15 +629,70 @@ impl CliApp {
                 self.save()?;
             }
             GameResult::Error(err) => {
-                write!(stdout, "😖 Failed to water crops: {}\r\n", err)?;
+                write!(stdout, "😖 Failed to craft: {}\r\n", err)?;
             }
         }
 
         Ok(())
     }
 
-    fn handle_harvest_crops(&mut self, stdout: &mut StdoutLock) -> Result<()> {
-        let result = self.game_engine.execute(GameCommand::HarvestCrops);
+    fn handle_status(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let info = self.engine().get_info();
+        GameRenderer::render_status(stdout, &info)?;
+        Ok(())
+    }
+
+    fn handle_crop_contest(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let info = self.engine().get_info();
+
+        match &info.festival {
+            Some(festival) if festival.crop_contest => {
+                write!(stdout, "🏆 {} crop contest\r\n", festival.name)?;
+            }
+            _ => {
+                write!(stdout, "😖 There is no crop contest today.\r\n")?;
+                return Ok(());
+            }
+        }
+
+        let ready: Vec<(usize, _)> = info
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, crop)| crop.ready_harvest)
+            .collect();
+
+        if ready.is_empty() {
+            write!(stdout, "😖 You have no fully grown crops to enter.\r\n")?;
+            return Ok(());
+        }
+
+        for (number, (_, crop)) in ready.iter().enumerate() {
+            write!(
+                stdout,
+                "{}. {} {} (Sell Price: {})\r\n",
+                number + 1,
+                crop.icon,
+                crop.name,
+                crop.sell_price
+            )?;
+        }
+
+        write!(stdout, "🌾 Select a crop to enter by number:\r\n")?;
+        let mut selected = String::new();
+        io::stdin().read_line(&mut selected)?;
+
+        let field_index = match selected.trim().parse::<usize>() {
+            Ok(n) if n > 0 && n <= ready.len() => ready[n - 1].0,
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        };
+
+        let result = self
+            .engine()
+            .execute(GameCommand::EnterCropContest { field_index });
 
         match result {
             GameResult::Success(event) => {
@@ -261,16 +700,555 @@ impl CliApp {
                 self.save()?;
             }
             GameResult::Error(err) => {
-                write!(stdout, "😖 Failed to harvest crops: {}\r\n", err)?;
+                write!(stdout, "😖 Failed to enter contest: {}\r\n", err)?;
             }
         }
 
         Ok(())
     }
 
-    fn handle_status(&mut self, stdout: &mut StdoutLock) -> Result<()> {
-        let info = self.game_engine.get_info();
-        GameRenderer::render_status(stdout, &info)?;
+    fn handle_fertilize(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let fertilizers = fertilizer_catalog();
+
+        write!(stdout, "🧪 Fertilizers:\r\n")?;
+        for (index, fertilizer) in fertilizers.iter().enumerate() {
+            write!(
+                stdout,
+                "{}. {} {} (+{}% fertility, owned: {}, price: {})\r\n",
+                index + 1,
+                fertilizer.icon,
+                fertilizer.name,
+                fertilizer.fertility,
+                self.engine().get_player().item_count(&fertilizer.name),
+                fertilizer.price
+            )?;
+        }
+
+        write!(stdout, "🧪 Select a fertilizer by number:\r\n")?;
+        let mut selected = String::new();
+        io::stdin().read_line(&mut selected)?;
+
+        let fertilizer = match selected.trim().parse::<usize>() {
+            Ok(n) if n > 0 && n <= fertilizers.len() => &fertilizers[n - 1],
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        };
+
+        // Buy a bag first if the player has none
+        if self.engine().get_player().item_count(&fertilizer.name) == 0 {
+            let result = self.engine().execute(GameCommand::BuyItem {
+                item_name: fertilizer.name.clone(),
+            });
+
+            match result {
+                GameResult::Success(event) => GameRenderer::render_event(stdout, &event)?,
+                GameResult::Error(err) => {
+                    write!(stdout, "😖 Failed to buy fertilizer: {}\r\n", err)?;
+                    return Ok(());
+                }
+            }
+        }
+
+        GameRenderer::render_soil(stdout, &self.engine().get_player().plots)?;
+        write!(stdout, "🗺️  Select a plot to fertilize by number:\r\n")?;
+        let mut plot_input = String::new();
+        io::stdin().read_line(&mut plot_input)?;
+
+        let plot = match plot_input.trim().parse::<usize>() {
+            Ok(plot) if plot > 0 => plot - 1,
+            _ => {
+                write!(stdout, "😖 Invalid plot.\r\n")?;
+                self.save()?;
+                return Ok(());
+            }
+        };
+
+        let result = self.engine().execute(GameCommand::ApplyFertilizer {
+            plot,
+            item_name: fertilizer.name.clone(),
+        });
+
+        match result {
+            GameResult::Success(event) => GameRenderer::render_event(stdout, &event)?,
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed to fertilize: {}\r\n", err)?;
+            }
+        }
+
+        self.save()?;
+        Ok(())
+    }
+
+    fn handle_sprinkler(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let info = self.engine().get_info();
+        let sprinklers = sprinkler_catalog();
+
+        write!(
+            stdout,
+            "💦 Sprinklers water crops in range every morning:\r\n"
+        )?;
+        for (index, kind) in sprinklers.iter().enumerate() {
+            write!(
+                stdout,
+                "{}. {} {} (waters {}, owned: {}, price: {})\r\n",
+                index + 1,
+                kind.icon(),
+                kind.name(),
+                kind.range_description(),
+                info.items.get(kind.name()).copied().unwrap_or(0),
+                kind.price()
+            )?;
+        }
+        write!(
+            stdout,
+            "{}. 🎒 Pick up a sprinkler\r\n",
+            sprinklers.len() + 1
+        )?;
+
+        write!(stdout, "💦 Select an option by number:\r\n")?;
+        let mut selected = String::new();
+        io::stdin().read_line(&mut selected)?;
+
+        let kind = match selected.trim().parse::<usize>() {
+            Ok(n) if n > 0 && n <= sprinklers.len() => Some(sprinklers[n - 1]),
+            Ok(n) if n == sprinklers.len() + 1 => None,
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        };
+
+        // Buy one first if the player has none
+        if let Some(kind) = kind
+            && self.engine().get_player().item_count(kind.name()) == 0
+        {
+            let result = self.engine().execute(GameCommand::BuyItem {
+                item_name: kind.name().to_string(),
+            });
+
+            match result {
+                GameResult::Success(event) => GameRenderer::render_event(stdout, &event)?,
+                GameResult::Error(err) => {
+                    write!(stdout, "😖 Failed to buy sprinkler: {}\r\n", err)?;
+                    return Ok(());
+                }
+            }
+        }
+
+        GameRenderer::render_farm(stdout, &info)?;
+        write!(stdout, "🗺️  Select a plot by number:\r\n")?;
+        let mut plot_input = String::new();
+        io::stdin().read_line(&mut plot_input)?;
+
+        let plot = match plot_input.trim().parse::<usize>() {
+            Ok(plot) if plot > 0 => plot - 1,
+            _ => {
+                write!(stdout, "😖 Invalid plot.\r\n")?;
+                self.save()?;
+                return Ok(());
+            }
+        };
+
+        let command = match kind {
+            Some(kind) => GameCommand::PlaceSprinkler {
+                plot,
+                item_name: kind.name().to_string(),
+            },
+            None => GameCommand::RemoveSprinkler { plot },
+        };
+
+        match self.engine().execute(command) {
+            GameResult::Success(event) => GameRenderer::render_event(stdout, &event)?,
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed: {}\r\n", err)?;
+            }
+        }
+
+        self.save()?;
+        Ok(())
+    }
+
+    fn handle_machine(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let info = self.engine().get_info();
+
+        GameRenderer::render_machines(stdout, &info)?;
+        write!(stdout, "1. 🏗️  Place a machine\r\n")?;
+        write!(stdout, "2. ⚙️  Load a machine\r\n")?;
+        write!(stdout, "3. 🧺 Collect finished products\r\n")?;
+        write!(stdout, "4. 🎒 Pick up a machine\r\n")?;
+        write!(stdout, "⚙️  Select an option by number:\r\n")?;
+
+        let mut selected = String::new();
+        io::stdin().read_line(&mut selected)?;
+
+        let command = match selected.trim() {
+            "1" => {
+                let machines = machine_catalog();
+                for (index, kind) in machines.iter().enumerate() {
+                    write!(
+                        stdout,
+                        "{}. {} {} (owned: {}, price: {})\r\n",
+                        index + 1,
+                        kind.icon(),
+                        kind.name(),
+                        info.items.get(kind.name()).copied().unwrap_or(0),
+                        kind.price()
+                    )?;
+                }
+
+                write!(stdout, "🏗️  Select a machine by number:\r\n")?;
+                let mut selected = String::new();
+                io::stdin().read_line(&mut selected)?;
+
+                let kind = match selected.trim().parse::<usize>() {
+                    Ok(n) if n > 0 && n <= machines.len() => machines[n - 1],
+                    _ => {
+                        write!(stdout, "😖 Invalid selection.\r\n")?;
+                        return Ok(());
+                    }
+                };
+
+                // Buy one first if the player has none
+                if self.engine().get_player().item_count(kind.name()) == 0 {
+                    let result = self.engine().execute(GameCommand::BuyItem {
+                        item_name: kind.name().to_string(),
+                    });
+
+                    match result {
+                        GameResult::Success(event) => GameRenderer::render_event(stdout, &event)?,
+                        GameResult::Error(err) => {
+                            write!(stdout, "😖 Failed to buy machine: {}\r\n", err)?;
+                            return Ok(());
+                        }
+                    }
+                }
+
+                GameRenderer::render_farm(stdout, &info)?;
+                write!(stdout, "🗺️  Select a plot by number:\r\n")?;
+                let Some(plot) = Self::read_plot(stdout)? else {
+                    self.save()?;
+                    return Ok(());
+                };
+
+                GameCommand::PlaceMachine {
+                    plot,
+                    item_name: kind.name().to_string(),
+                }
+            }
+            "2" => {
+                let idle: Vec<_> = info
+                    .machines
+                    .iter()
+                    .filter(|machine| machine.processing.is_none())
+                    .collect();
+
+                if idle.is_empty() {
+                    write!(stdout, "😖 You have no idle machines.\r\n")?;
+                    return Ok(());
+                }
+
+                for (index, machine) in idle.iter().enumerate() {
+                    write!(
+                        stdout,
+                        "{}. {} {} (plot {})\r\n",
+                        index + 1,
+                        machine.kind.icon(),
+                        machine.kind.name(),
+                        machine.plot + 1
+                    )?;
+                }
+
+                write!(stdout, "⚙️  Select a machine by number:\r\n")?;
+                let mut selected = String::new();
+                io::stdin().read_line(&mut selected)?;
+
+                let machine = match selected.trim().parse::<usize>() {
+                    Ok(n) if n > 0 && n <= idle.len() => idle[n - 1],
+                    _ => {
+                        write!(stdout, "😖 Invalid selection.\r\n")?;
+                        return Ok(());
+                    }
+                };
+
+                let inputs: Vec<_> = info
+                    .items
+                    .iter()
+                    .filter_map(|(name, count)| {
+                        find_product(machine.kind, name).map(|product| (product, *count))
+                    })
+                    .collect();
+
+                if inputs.is_empty() {
+                    write!(
+                        stdout,
+                        "😖 You have nothing a {} can process. Gather some crops first!\r\n",
+                        machine.kind.name()
+                    )?;
+                    return Ok(());
+                }
+
+                for (index, (product, count)) in inputs.iter().enumerate() {
+                    write!(
+                        stdout,
+                        "{}. {} x{} → {} ({} day(s), sells for {})\r\n",
+                        index + 1,
+                        product.input,
+                        count,
+                        product.output,
+                        product.days,
+                        product.sell_price
+                    )?;
+                }
+
+                write!(stdout, "⚙️  Select an item by number:\r\n")?;
+                let mut selected = String::new();
+                io::stdin().read_line(&mut selected)?;
+
+                match selected.trim().parse::<usize>() {
+                    Ok(n) if n > 0 && n <= inputs.len() => GameCommand::LoadMachine {
+                        plot: machine.plot,
+                        item_name: inputs[n - 1].0.input.clone(),
+                    },
+                    _ => {
+                        write!(stdout, "😖 Invalid selection.\r\n")?;
+                        return Ok(());
+                    }
+                }
+            }
+            "3" => GameCommand::CollectMachineProducts,
+            "4" => {
+                write!(stdout, "🗺️  Select the plot of the machine to pick up:\r\n")?;
+                let Some(plot) = Self::read_plot(stdout)? else {
+                    return Ok(());
+                };
+
+                GameCommand::RemoveMachine { plot }
+            }
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        };
+
+        match self.engine().execute(command) {
+            GameResult::Success(event) => GameRenderer::render_event(stdout, &event)?,
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed: {}\r\n", err)?;
+            }
+        }
+
+        self.save()?;
+        Ok(())
+    }
+
+    fn handle_animals(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let info = self.engine().get_info();
+
+        GameRenderer::render_animals(stdout, &info)?;
+        write!(stdout, "1. 🌾 Feed animals\r\n")?;
+        write!(stdout, "2. 🏪 Buy hay ({} coins a bale)\r\n", HAY_PRICE)?;
+        write!(stdout, "3. 🐣 Buy an animal\r\n")?;
+        write!(stdout, "4. 🔨 Build a coop or barn\r\n")?;
+        write!(stdout, "🐄 Select an option by number:\r\n")?;
+
+        let mut selected = String::new();
+        io::stdin().read_line(&mut selected)?;
+
+        match selected.trim() {
+            "1" => {
+                let result = self.engine().execute(GameCommand::FeedAnimals);
+                self.render_result(stdout, result)?;
+            }
+            "2" => {
+                write!(stdout, "🌾 How many bales?\r\n")?;
+                let mut quantity = String::new();
+                io::stdin().read_line(&mut quantity)?;
+
+                let Ok(quantity) = quantity.trim().parse::<u32>() else {
+                    write!(stdout, "😖 Invalid quantity.\r\n")?;
+                    return Ok(());
+                };
+
+                for _ in 0..quantity {
+                    let result = self.engine().execute(GameCommand::BuyItem {
+                        item_name: "Hay".to_string(),
+                    });
+                    if !self.render_result(stdout, result)? {
+                        break;
+                    }
+                }
+            }
+            "3" => {
+                let animals = animal_catalog();
+                for (index, kind) in animals.iter().enumerate() {
+                    write!(
+                        stdout,
+                        "{}. {} {} (price: {}, lives in a {}, gives {})\r\n",
+                        index + 1,
+                        kind.icon(),
+                        kind.name(),
+                        kind.price(),
+                        kind.housing().name(),
+                        kind.product()
+                    )?;
+                }
+
+                write!(stdout, "🐣 Select an animal by number:\r\n")?;
+                let mut selected = String::new();
+                io::stdin().read_line(&mut selected)?;
+
+                let kind: AnimalKind = match selected.trim().parse::<usize>() {
+                    Ok(n) if n > 0 && n <= animals.len() => animals[n - 1],
+                    _ => {
+                        write!(stdout, "😖 Invalid selection.\r\n")?;
+                        return Ok(());
+                    }
+                };
+
+                write!(stdout, "📝 Name your {}:\r\n", kind.name())?;
+                let mut name = String::new();
+                io::stdin().read_line(&mut name)?;
+
+                let result = self.engine().execute(GameCommand::BuyAnimal {
+                    kind,
+                    name: name.trim().to_string(),
+                });
+                self.render_result(stdout, result)?;
+            }
+            "4" => {
+                let buildings = [BuildingKind::Coop, BuildingKind::Barn];
+                for (index, building) in buildings.iter().enumerate() {
+                    write!(
+                        stdout,
+                        "{}. {} {} (price: {})\r\n",
+                        index + 1,
+                        building.icon(),
+                        building.name(),
+                        building.price()
+                    )?;
+                }
+
+                write!(stdout, "🔨 Select a building by number:\r\n")?;
+                let mut selected = String::new();
+                io::stdin().read_line(&mut selected)?;
+
+                let building = match selected.trim().parse::<usize>() {
+                    Ok(n) if n > 0 && n <= buildings.len() => buildings[n - 1],
+                    _ => {
+                        write!(stdout, "😖 Invalid selection.\r\n")?;
+                        return Ok(());
+                    }
+                };
+
+                let result = self.engine().execute(GameCommand::Build { building });
+                self.render_result(stdout, result)?;
+            }
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        }
+
+        self.save()?;
+        Ok(())
+    }
+
+    /// Render a command result, returning whether it succeeded
+    fn render_result(&self, stdout: &mut StdoutLock, result: GameResult) -> Result<bool> {
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(stdout, &event)?;
+                Ok(true)
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 {}\r\n", err)?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Read a 1-based plot number, returning its index
+    fn read_plot(stdout: &mut StdoutLock) -> Result<Option<usize>> {
+        let mut plot_input = String::new();
+        io::stdin().read_line(&mut plot_input)?;
+
+        match plot_input.trim().parse::<usize>() {
+            Ok(plot) if plot > 0 => Ok(Some(plot - 1)),
+            _ => {
+                write!(stdout, "😖 Invalid plot.\r\n")?;
+                Ok(None)
+            }
+        }
+    }
+
+    fn handle_settings(&mut self, stdout: &mut StdoutLock) -> Result<()> {
+        let config = self.engine().get_time_config().clone();
+
+        write!(stdout, "⚙️  Settings\r\n")?;
+        write!(
+            stdout,
+            "Current time mode: {} ({} minutes per day)\r\n",
+            config.mode.name(),
+            config.auto_day_change_minutes
+        )?;
+        write!(stdout, "1. Real-time (days advance automatically)\r\n")?;
+        write!(stdout, "2. Turn-based (days advance when you sleep)\r\n")?;
+        write!(stdout, "3. Paused (days never advance)\r\n")?;
+        write!(stdout, "4. Cancel\r\n")?;
+
+        let mut selected = String::new();
+        io::stdin().read_line(&mut selected)?;
+
+        let mode = match selected.trim().parse::<usize>() {
+            Ok(1) => TimeMode::RealTime,
+            Ok(2) => TimeMode::TurnBased,
+            Ok(3) => TimeMode::Paused,
+            Ok(4) => return Ok(()),
+            _ => {
+                write!(stdout, "😖 Invalid selection.\r\n")?;
+                return Ok(());
+            }
+        };
+
+        let mut minutes_per_day = None;
+        if mode == TimeMode::RealTime {
+            write!(
+                stdout,
+                "Minutes per day (press Enter to keep {}):\r\n",
+                config.auto_day_change_minutes
+            )?;
+            let mut minutes = String::new();
+            io::stdin().read_line(&mut minutes)?;
+
+            let minutes = minutes.trim();
+            if !minutes.is_empty() {
+                match minutes.parse::<i64>() {
+                    Ok(minutes) => minutes_per_day = Some(minutes),
+                    Err(_) => {
+                        write!(stdout, "😖 Invalid input.\r\n")?;
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        let result = self.engine().execute(GameCommand::SetTimeMode {
+            mode,
+            minutes_per_day,
+        });
+
+        match result {
+            GameResult::Success(event) => {
+                GameRenderer::render_event(stdout, &event)?;
+                self.save()?;
+            }
+            GameResult::Error(err) => {
+                write!(stdout, "😖 Failed to change time mode: {}\r\n", err)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -279,7 +1257,7 @@ impl CliApp {
         use crate::network::trade_ui;
 
         if let Some(ref trade_manager) = self.trade_manager {
-            trade_ui::handle_trade(stdout, trade_manager, &mut self.game_engine)?;
+            trade_ui::handle_trade(stdout, trade_manager, &self.game_engine)?;
             self.save()?;
         } else {
             write!(stdout, "❌ Trade manager not initialized\r\n")?;
@@ -289,36 +1267,18 @@ impl CliApp {
     }
 }
 
-async fn monitor_day_changes(
+/// Tick the engine every few seconds, forwarding each new day's event
+pub(crate) async fn monitor_day_changes(
     game_engine: Arc<Mutex<GameEngine>>,
     day_tx: mpsc::UnboundedSender<crate::core::types::GameEvent>,
 ) {
-    use chrono::{Duration, Utc};
-
     let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(10));
 
     loop {
         interval.tick().await;
 
-        let mut engine = game_engine.lock().await;
-        let config = engine.get_time_config();
-
-        if let Some(last_change) = config.last_day_change {
-            let now = Utc::now();
-            let elapsed = now.signed_duration_since(last_change);
-            let auto_minutes = Duration::minutes(config.auto_day_change_minutes);
-
-            if elapsed >= auto_minutes {
-                let days_passed = elapsed.num_minutes() / config.auto_day_change_minutes;
-
-                for _ in 0..days_passed {
-                    let result = engine.execute(GameCommand::AdvanceDay);
-
-                    if let GameResult::Success(event) = result {
-                        let _ = day_tx.send(event);
-                    }
-                }
-            }
+        for event in game_engine.lock().await.tick(chrono::Utc::now()) {
+            let _ = day_tx.send(event);
         }
     }
 }
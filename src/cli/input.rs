@@ -1,25 +1,269 @@
+use anyhow::Result;
+use crossterm::{
+    QueueableCommand, cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal::{self, Clear, ClearType},
+};
+use std::io::Write;
+use std::time::Duration;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum InputEvent {
     Sleep,
     PlantCrop,
     WaterCrops,
     HarvestCrops,
+    GatherCrops,
+    SellItem,
+    Shop,
+    Craft,
     Status,
     Trade,
+    Contest,
+    Fertilize,
+    Sprinkler,
+    Machine,
+    Animals,
+    Settings,
     Quit,
 }
 
+/// Command words offered by the command palette
+pub const COMMANDS: &[&str] = &[
+    "plant",
+    "water",
+    "harvest",
+    "gather",
+    "sell",
+    "shop",
+    "craft",
+    "fertilize",
+    "sprinkler",
+    "machine",
+    "animals",
+    "sleep",
+    "status",
+    "contest",
+    "trade",
+    "settings",
+    "quit",
+];
+
 impl InputEvent {
-    pub fn from_str(input: &str) -> Option<Self> {
+    pub fn parse(input: &str) -> Option<Self> {
         match input.trim().to_lowercase().as_str() {
             "sleep" | "s" => Some(InputEvent::Sleep),
             "plant" | "p" => Some(InputEvent::PlantCrop),
             "water" | "w" => Some(InputEvent::WaterCrops),
             "harvest" | "h" => Some(InputEvent::HarvestCrops),
+            "gather" | "g" => Some(InputEvent::GatherCrops),
+            "sell" | "l" => Some(InputEvent::SellItem),
+            "shop" | "buy" | "b" => Some(InputEvent::Shop),
+            "craft" | "r" => Some(InputEvent::Craft),
             "status" | "i" => Some(InputEvent::Status),
             "trade" | "t" => Some(InputEvent::Trade),
+            "contest" | "c" => Some(InputEvent::Contest),
+            "fertilize" | "f" => Some(InputEvent::Fertilize),
+            "sprinkler" | "k" => Some(InputEvent::Sprinkler),
+            "machine" | "m" => Some(InputEvent::Machine),
+            "animals" | "a" => Some(InputEvent::Animals),
+            "settings" | "o" => Some(InputEvent::Settings),
             "quit" | "q" => Some(InputEvent::Quit),
             _ => None,
         }
     }
+
+    /// Command bound to a single-key hotkey: the same letters as the short aliases
+    pub fn from_key(key: KeyEvent) -> Option<Self> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Some(InputEvent::Quit);
+        }
+
+        match key.code {
+            KeyCode::Char(c) => Self::parse(&c.to_string()),
+            KeyCode::Esc => Some(InputEvent::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Terminal raw mode for as long as the guard lives
+pub struct RawMode;
+
+impl RawMode {
+    pub fn enable() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        terminal::disable_raw_mode().ok();
+    }
+}
+
+/// Block until a key is pressed, calling `on_idle` regularly while waiting
+pub fn wait_for_key(mut on_idle: impl FnMut() -> Result<()>) -> Result<KeyEvent> {
+    let _raw = RawMode::enable()?;
+
+    loop {
+        on_idle()?;
+
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            return Ok(key);
+        }
+    }
+}
+
+fn read_key() -> Result<KeyEvent> {
+    loop {
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            return Ok(key);
+        }
+    }
+}
+
+/// Let the player choose one of `options` with the arrow keys.
+///
+/// Returns the chosen index, or `None` if the player pressed Esc.
+pub fn pick(stdout: &mut impl Write, options: &[String]) -> Result<Option<usize>> {
+    if options.is_empty() {
+        return Ok(None);
+    }
+
+    let _raw = RawMode::enable()?;
+    let mut selected = 0;
+
+    loop {
+        for (index, option) in options.iter().enumerate() {
+            stdout.queue(Clear(ClearType::CurrentLine))?;
+            let marker = if index == selected { "▶" } else { " " };
+            write!(stdout, "{} {}\r\n", marker, option)?;
+        }
+        write!(stdout, "↑/↓ to move, Enter to choose, Esc to cancel")?;
+        stdout.flush()?;
+
+        let key = read_key()?;
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => selected = selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => selected = (selected + 1).min(options.len() - 1),
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                let digit = c.to_digit(10).unwrap_or(0) as usize;
+                if digit > 0 && digit <= options.len() {
+                    selected = digit - 1;
+                }
+            }
+            _ => {}
+        }
+
+        // Replace the hint line, then redraw the options in place
+        stdout.queue(cursor::MoveToColumn(0))?;
+        stdout.queue(Clear(ClearType::CurrentLine))?;
+
+        let cancelled = key.code == KeyCode::Esc
+            || key.code == KeyCode::Char('q')
+            || (key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c'));
+        if cancelled {
+            return Ok(None);
+        }
+        if key.code == KeyCode::Enter {
+            return Ok(Some(selected));
+        }
+
+        stdout.queue(cursor::MoveUp(options.len() as u16))?;
+    }
+}
+
+/// Read a `:` command line, completing command words and crop names with Tab.
+///
+/// Returns `None` if the player pressed Esc.
+pub fn read_command(stdout: &mut impl Write, crops: &[String]) -> Result<Option<String>> {
+    let _raw = RawMode::enable()?;
+    let mut line = String::new();
+
+    loop {
+        stdout.queue(cursor::MoveToColumn(0))?;
+        stdout.queue(Clear(ClearType::CurrentLine))?;
+        write!(stdout, ":{}", line)?;
+        stdout.flush()?;
+
+        let key = read_key()?;
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            write!(stdout, "\r\n")?;
+            return Ok(None);
+        }
+
+        match key.code {
+            KeyCode::Enter => {
+                write!(stdout, "\r\n")?;
+                return Ok(Some(line.trim().to_string()));
+            }
+            KeyCode::Esc => {
+                write!(stdout, "\r\n")?;
+                return Ok(None);
+            }
+            KeyCode::Backspace => {
+                line.pop();
+            }
+            KeyCode::Tab => {
+                let matches = complete(&mut line, crops);
+                if matches.len() > 1 {
+                    write!(stdout, "\r\n{}\r\n", matches.join("  "))?;
+                }
+            }
+            KeyCode::Char(c) => line.push(c),
+            _ => {}
+        }
+    }
+}
+
+/// Complete the word being typed, returning every candidate that matched
+fn complete(line: &mut String, crops: &[String]) -> Vec<String> {
+    // The first word is a command; everything after it is a crop name
+    let (prefix, fragment, candidates): (String, String, Vec<String>) = match line.split_once(' ') {
+        Some((command, rest)) => (format!("{} ", command), rest.to_string(), crops.to_vec()),
+        None => (
+            String::new(),
+            line.clone(),
+            COMMANDS.iter().map(|c| c.to_string()).collect(),
+        ),
+    };
+
+    let fragment = fragment.to_lowercase();
+    let matches: Vec<String> = candidates
+        .into_iter()
+        .filter(|candidate| candidate.to_lowercase().starts_with(&fragment))
+        .collect();
+
+    match matches.as_slice() {
+        [] => {}
+        // A completed command is followed by its crop name argument
+        [only] if prefix.is_empty() => *line = format!("{} ", only),
+        [only] => *line = format!("{}{}", prefix, only),
+        [first, rest @ ..] => {
+            let common = rest.iter().fold(first.clone(), |common, candidate| {
+                common
+                    .chars()
+                    .zip(candidate.chars())
+                    .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+                    .map(|(a, _)| a)
+                    .collect()
+            });
+
+            if common.len() > fragment.len() {
+                *line = format!("{}{}", prefix, common);
+            }
+        }
+    }
+
+    matches
 }
@@ -2,6 +2,8 @@ pub mod app;
 pub mod input;
 pub mod persistence;
 pub mod renderer;
+pub mod script;
+pub mod tui;
 
 // Re-export commonly used items
 pub use app::CliApp;
@@ -1,67 +1,141 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use std::{
-    fs::{File, OpenOptions},
-    io::{Read, Write},
-    path::Path,
+    fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    sync::OnceLock,
 };
 
-use crate::core::GameEngine;
+use super::renderer::GameRenderer;
+use crate::core::{
+    GameEngine, SaveBundle,
+    persistence::{DEFAULT_SLOT, SaveSlots, SaveStorage, validate_slot},
+};
+
+/// Slot picked with `--slot`, used by every save and load in this run
+static SLOT: OnceLock<String> = OnceLock::new();
+
+/// Stores each key as a hidden JSON file in the working directory, e.g. `.game-state.json`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileStorage;
+
+impl FileStorage {
+    fn path(key: &str) -> PathBuf {
+        PathBuf::from(format!(".{}.json", key))
+    }
+}
 
-const STATE_FILE: &str = ".game-state.json";
+impl SaveStorage for FileStorage {
+    fn read(&self, key: &str) -> Result<Option<String>> {
+        let path = Self::path(key);
+        match fs::read_to_string(&path) {
+            Ok(content) => Ok(Some(content)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => {
+                Err(err).with_context(|| format!("Failed to read save file {}", path.display()))
+            }
+        }
+    }
+
+    fn write(&self, key: &str, value: &str) -> Result<()> {
+        let path = Self::path(key);
+        fs::write(&path, value).with_context(|| format!("Failed writing file {}", path.display()))
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        let path = Self::path(key);
+        match fs::remove_file(&path) {
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                Err(err).with_context(|| format!("Failed to delete {}", path.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn keys(&self) -> Result<Vec<String>> {
+        let entries = fs::read_dir(".").context("Failed to list save files")?;
+
+        Ok(entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|name| Some(name.strip_prefix('.')?.strip_suffix(".json")?.to_string()))
+            .collect())
+    }
+}
 
 pub struct GamePersistence;
 
 impl GamePersistence {
-    /// Load game state from file, or return None if file doesn't exist
-    pub fn load() -> Result<Option<GameEngine>> {
-        if !Path::new(STATE_FILE).exists() {
-            return Ok(None);
-        }
+    /// Use a save slot other than the default for the rest of the run
+    pub fn select_slot(slot: &str) -> Result<()> {
+        validate_slot(slot)?;
+        SLOT.set(slot.to_string())
+            .map_err(|_| anyhow!("A save slot was already selected"))
+    }
 
-        let mut file = File::open(STATE_FILE)
-            .with_context(|| format!("Failed to open game state file {}", STATE_FILE))?;
+    /// The save slot in use
+    pub fn slot() -> &'static str {
+        SLOT.get().map_or(DEFAULT_SLOT, String::as_str)
+    }
+
+    /// Save slots kept in the working directory
+    pub fn slots() -> SaveSlots<FileStorage> {
+        SaveSlots::new(FileStorage)
+    }
 
-        let mut content = String::new();
-        file.read_to_string(&mut content)
-            .with_context(|| "Failed to read game state file")?;
+    /// Load game state from the save slot, or return None if it is empty
+    pub fn load() -> Result<Option<GameEngine>> {
+        Self::slots().load(Self::slot())
+    }
+
+    /// Load the game from the save slot, simulating the days missed since it was
+    /// last played and rendering what happened to `out`
+    pub fn resume(out: &mut impl Write) -> Result<Option<GameEngine>> {
+        let Some(mut game_engine) = Self::load()? else {
+            return Ok(None);
+        };
 
-        let game_engine: GameEngine =
-            serde_json::from_str(&content).with_context(|| "Failed to parse game state file")?;
+        if let Some(event) = game_engine.catch_up(chrono::Utc::now()) {
+            GameRenderer::render_event(out, &event)?;
+            Self::save(&game_engine)?;
+        }
 
         Ok(Some(game_engine))
     }
 
-    /// Save game state to file
+    /// Save game state to the save slot, backing up the previous day's save
     pub fn save(game_engine: &GameEngine) -> Result<()> {
-        let mut file = OpenOptions::new()
-            .write(true)
-            .read(true)
-            .truncate(true)
-            .create(true)
-            .open(STATE_FILE)
-            .with_context(|| format!("Failed to create/open {}", STATE_FILE))?;
+        Self::slots().save(Self::slot(), game_engine)
+    }
+
+    /// Delete the save, keeping its backups
+    pub fn reset() -> Result<()> {
+        Self::slots().delete(Self::slot())
+    }
 
-        let json = serde_json::to_string_pretty(game_engine)
-            .with_context(|| "Failed to serialize game state")?;
+    /// Export the current save as a portable bundle file
+    pub fn export_to(path: &Path) -> Result<()> {
+        let game_engine = Self::load()?.context("No saved game to export")?;
+        let bundle = SaveBundle::export(&game_engine)?;
 
-        file.write_all(json.as_bytes())
-            .with_context(|| format!("Failed writing file {}", STATE_FILE))?;
+        fs::write(path, bundle)
+            .with_context(|| format!("Failed writing bundle file {}", path.display()))?;
 
         Ok(())
     }
 
-    /// Delete the save file
-    pub fn reset() -> Result<()> {
-        if Path::new(STATE_FILE).exists() {
-            std::fs::remove_file(STATE_FILE)
-                .with_context(|| format!("Failed to delete {}", STATE_FILE))?;
-        }
+    /// Import a portable bundle file, replacing the current save
+    pub fn import_from(path: &Path) -> Result<GameEngine> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read bundle file {}", path.display()))?;
 
-        Ok(())
+        let game_engine = SaveBundle::import(&content)?;
+        Self::save(&game_engine)?;
+
+        Ok(game_engine)
     }
 
-    /// Check if a save file exists
+    /// Check if the save slot holds a save
     pub fn save_exists() -> bool {
-        Path::new(STATE_FILE).exists()
+        Self::slots().exists(Self::slot()).unwrap_or(false)
     }
 }
@@ -3,19 +3,28 @@ use crossterm::{
     QueueableCommand,
     style::{Color, ResetColor, SetForegroundColor},
 };
-use std::io::{StdoutLock, Write};
+use std::io::Write;
 
-use crate::core::types::{GameEvent, GameInfo, SeasonChangeEvent};
+use crate::core::{
+    animal::BuildingKind,
+    farm::{FARM_HEIGHT, FARM_WIDTH, plot_at, plot_map},
+    festival::Festival,
+    soil::Plot,
+    types::{GameEvent, GameInfo, SeasonChangeEvent, SeasonWarning, TimeMode},
+};
 
 pub struct GameRenderer;
 
 impl GameRenderer {
-    pub fn render_event(stdout: &mut StdoutLock, event: &GameEvent) -> Result<()> {
+    pub fn render_event(stdout: &mut impl Write, event: &GameEvent) -> Result<()> {
         match event {
             GameEvent::Slept {
                 old_day,
                 new_day,
                 season_change,
+                overnight,
+                season_warning,
+                festival,
             } => {
                 write!(stdout, "💤 Good night...\r\n")?;
                 write!(stdout, "🌞 End of day {}\r\n", old_day)?;
@@ -26,11 +35,27 @@ impl GameRenderer {
 
                 write!(stdout, "🌞 Welcome to day {}!\r\n", new_day)?;
                 write!(stdout, "💤 You feel well rested! Energy restored.\r\n")?;
+
+                for event in overnight {
+                    Self::render_event(stdout, event)?;
+                }
+
+                if let Some(warning) = season_warning {
+                    Self::render_season_warning(stdout, warning)?;
+                }
+
+                if let Some(festival) = festival {
+                    Self::render_festival(stdout, festival)?;
+                }
+
                 write!(stdout, "💾 Save completed...\r\n")?;
             }
             GameEvent::DayAdvanced {
                 new_day,
                 season_change,
+                overnight,
+                season_warning,
+                festival,
             } => {
                 write!(stdout, "⏰ Time has passed! A new day has begun!\r\n")?;
                 write!(stdout, "🌞 Welcome to day {}!\r\n", new_day)?;
@@ -39,15 +64,50 @@ impl GameRenderer {
                 if let Some(change) = season_change {
                     Self::render_season_change(stdout, change)?;
                 }
+
+                for event in overnight {
+                    Self::render_event(stdout, event)?;
+                }
+
+                if let Some(warning) = season_warning {
+                    Self::render_season_warning(stdout, warning)?;
+                }
+
+                if let Some(festival) = festival {
+                    Self::render_festival(stdout, festival)?;
+                }
             }
             GameEvent::CropPlanted {
                 crop_name,
+                plot,
                 remaining_energy,
             } => {
                 write!(
                     stdout,
-                    "🌾 You have planted a {}. Remaining energy: {}\r\n",
-                    crop_name, remaining_energy
+                    "🌾 You have planted a {} in plot {}. Remaining energy: {}\r\n",
+                    crop_name,
+                    plot + 1,
+                    remaining_energy
+                )?;
+            }
+            GameEvent::GiantCropFormed { crop_name, plot } => {
+                write!(
+                    stdout,
+                    "🌟 Your {} crops at plot {} merged into a GIANT {}!\r\n",
+                    crop_name,
+                    plot + 1,
+                    crop_name
+                )?;
+            }
+            GameEvent::CropMutated {
+                parent_a,
+                parent_b,
+                seed_name,
+            } => {
+                write!(
+                    stdout,
+                    "🧬 Your {} and {} cross-pollinated! You found a {} seed.\r\n",
+                    parent_a, parent_b, seed_name
                 )?;
             }
             GameEvent::CropsWatered { remaining_energy } => {
@@ -60,12 +120,30 @@ impl GameRenderer {
             GameEvent::CropsHarvested {
                 earnings,
                 total_money,
+                extra_yields,
+                regrowing,
             } => {
                 write!(
                     stdout,
                     "🌾 You have harvested your crops and earned {} coins! Total money: {}\r\n",
                     earnings, total_money
                 )?;
+
+                if *extra_yields > 0 {
+                    write!(
+                        stdout,
+                        "🍀 Bountiful harvest! {} crop(s) gave an extra yield.\r\n",
+                        extra_yields
+                    )?;
+                }
+
+                if !regrowing.is_empty() {
+                    write!(
+                        stdout,
+                        "🌱 These crops will grow back: {}\r\n",
+                        regrowing.join(", ")
+                    )?;
+                }
             }
             GameEvent::EnergyRestored => {
                 write!(stdout, "⚡ Energy restored!\r\n")?;
@@ -81,13 +159,504 @@ impl GameRenderer {
                     seed_name, cost, remaining_money
                 )?;
             }
+            GameEvent::LeveledUp {
+                after,
+                level,
+                perks,
+            } => {
+                Self::render_event(stdout, after)?;
+                write!(
+                    stdout,
+                    "⭐ Farming level up! You are now level {}\r\n",
+                    level
+                )?;
+                for perk in perks {
+                    write!(stdout, "  ✨ {}\r\n", perk)?;
+                }
+            }
+            GameEvent::PassedOut {
+                after,
+                money_lost,
+                new_day,
+                remaining_energy,
+                season_change,
+                overnight,
+                season_warning,
+                festival,
+            } => {
+                Self::render_event(stdout, after)?;
+                write!(stdout, "🥴 It's 2am... you passed out from exhaustion!\r\n")?;
+                write!(
+                    stdout,
+                    "🪙 Someone found you and charged {} coins to bring you home.\r\n",
+                    money_lost
+                )?;
+
+                if let Some(change) = season_change {
+                    Self::render_season_change(stdout, change)?;
+                }
+
+                write!(stdout, "🌞 Welcome to day {}!\r\n", new_day)?;
+                write!(
+                    stdout,
+                    "😴 You wake up tired. Energy: {}\r\n",
+                    remaining_energy
+                )?;
+
+                for event in overnight {
+                    Self::render_event(stdout, event)?;
+                }
+
+                if let Some(warning) = season_warning {
+                    Self::render_season_warning(stdout, warning)?;
+                }
+
+                if let Some(festival) = festival {
+                    Self::render_festival(stdout, festival)?;
+                }
+            }
+            GameEvent::CropContestJudged {
+                festival_name,
+                crop_name,
+                score,
+                placement,
+                prize,
+                total_money,
+            } => {
+                let medal = match placement {
+                    1 => "🥇",
+                    2 => "🥈",
+                    _ => "🥉",
+                };
+
+                write!(
+                    stdout,
+                    "🏆 The {} judges scored your {} {} points.\r\n",
+                    festival_name, crop_name, score
+                )?;
+                write!(
+                    stdout,
+                    "{} Place #{}! You won {} coins. Total money: {}\r\n",
+                    medal, placement, prize, total_money
+                )?;
+            }
+            GameEvent::TimeModeChanged {
+                mode,
+                minutes_per_day,
+            } => {
+                write!(stdout, "⏱️  Time mode set to {}", mode.name())?;
+                if *mode == TimeMode::RealTime {
+                    write!(stdout, " ({} minutes per day)", minutes_per_day)?;
+                }
+                write!(stdout, "\r\n")?;
+            }
+            GameEvent::OfflineCatchUp {
+                days_passed,
+                days_simulated,
+                season_changes,
+                crops_ready,
+            } => {
+                write!(stdout, "⏳ While you were away...\r\n")?;
+                write!(stdout, "🗓️  {} day(s) passed on your farm\r\n", days_passed)?;
+
+                if days_simulated < days_passed {
+                    write!(
+                        stdout,
+                        "   (only {} day(s) were simulated)\r\n",
+                        days_simulated
+                    )?;
+                }
+
+                for change in season_changes {
+                    Self::render_season_change(stdout, change)?;
+                }
+
+                if crops_ready.is_empty() {
+                    write!(stdout, "🌱 No crops are ready to harvest.\r\n")?;
+                } else {
+                    write!(stdout, "🌾 Crops ready to harvest:\r\n")?;
+                    for crop_name in crops_ready {
+                        write!(stdout, "      - {}\r\n", crop_name)?;
+                    }
+                }
+            }
+            GameEvent::ItemPurchased {
+                item_name,
+                cost,
+                remaining_money,
+            } => {
+                write!(
+                    stdout,
+                    "🏪 Purchased {} for {} coins! Remaining money: {}\r\n",
+                    item_name, cost, remaining_money
+                )?;
+            }
+            GameEvent::FertilizerApplied {
+                item_name,
+                plot,
+                fertility,
+            } => {
+                write!(
+                    stdout,
+                    "🧪 Spread {} on plot {}. Soil fertility: {}%\r\n",
+                    item_name,
+                    plot + 1,
+                    fertility
+                )?;
+            }
+            GameEvent::SprinklerPlaced { item_name, plot } => {
+                write!(
+                    stdout,
+                    "💦 Placed a {} on plot {}.\r\n",
+                    item_name,
+                    plot + 1
+                )?;
+            }
+            GameEvent::SprinklerRemoved { item_name, plot } => {
+                write!(
+                    stdout,
+                    "🎒 Picked up the {} from plot {}.\r\n",
+                    item_name,
+                    plot + 1
+                )?;
+            }
+            GameEvent::SprinklersWatered { crops } => {
+                write!(
+                    stdout,
+                    "💦 Your sprinklers watered: {}\r\n",
+                    crops.join(", ")
+                )?;
+            }
+            GameEvent::CropsGathered {
+                produce,
+                earnings,
+                quality_bonus,
+                total_money,
+                regrowing,
+            } => {
+                write!(stdout, "🧺 Gathered your harvest:\r\n")?;
+                for (name, count) in produce {
+                    write!(stdout, "      - {} x{}\r\n", name, count)?;
+                }
+                if *quality_bonus > 0 {
+                    write!(
+                        stdout,
+                        "🥇 Earned {} coins for the quality of your produce\r\n",
+                        quality_bonus
+                    )?;
+                }
+                if *earnings > 0 {
+                    write!(
+                        stdout,
+                        "🪙 Sold crops you couldn't store for {} coins. Total money: {}\r\n",
+                        earnings, total_money
+                    )?;
+                }
+                if !regrowing.is_empty() {
+                    write!(
+                        stdout,
+                        "🌱 These crops will grow back: {}\r\n",
+                        regrowing.join(", ")
+                    )?;
+                }
+            }
+            GameEvent::ItemSold {
+                item_name,
+                quantity,
+                earnings,
+                total_money,
+            } => {
+                write!(
+                    stdout,
+                    "🪙 Sold {} x{} for {} coins! Total money: {}\r\n",
+                    item_name, quantity, earnings, total_money
+                )?;
+            }
+            GameEvent::CraftingStarted {
+                recipe_name,
+                ready_day,
+            } => {
+                write!(
+                    stdout,
+                    "🔨 Started crafting {}. It will be ready on day {}.\r\n",
+                    recipe_name, ready_day
+                )?;
+            }
+            GameEvent::CraftingFinished { items } => {
+                write!(stdout, "🔨 Finished crafting: {}\r\n", items.join(", "))?;
+            }
+            GameEvent::MachinePlaced { item_name, plot } => {
+                write!(
+                    stdout,
+                    "🏗️  Placed a {} on plot {}.\r\n",
+                    item_name,
+                    plot + 1
+                )?;
+            }
+            GameEvent::MachineRemoved { item_name, plot } => {
+                write!(
+                    stdout,
+                    "🎒 Picked up the {} from plot {}.\r\n",
+                    item_name,
+                    plot + 1
+                )?;
+            }
+            GameEvent::MachineLoaded {
+                machine_name,
+                input,
+                output,
+                ready_day,
+            } => {
+                write!(
+                    stdout,
+                    "⚙️  Put {} in the {}. {} will be ready on day {}.\r\n",
+                    input, machine_name, output, ready_day
+                )?;
+            }
+            GameEvent::MachinesFinished { products } => {
+                write!(
+                    stdout,
+                    "⚙️  Ready to collect from your machines: {}\r\n",
+                    products.join(", ")
+                )?;
+            }
+            GameEvent::MachineProductsCollected { products } => {
+                write!(stdout, "🧺 Collected: {}\r\n", products.join(", "))?;
+            }
+            GameEvent::BuildingBuilt {
+                building,
+                cost,
+                remaining_money,
+            } => {
+                write!(
+                    stdout,
+                    "{} Built a {} for {} coins! Remaining money: {}\r\n",
+                    building.icon(),
+                    building.name(),
+                    cost,
+                    remaining_money
+                )?;
+            }
+            GameEvent::AnimalPurchased {
+                name,
+                kind,
+                cost,
+                remaining_money,
+            } => {
+                write!(
+                    stdout,
+                    "{} Welcome home, {} the {}! Cost: {} coins. Remaining money: {}\r\n",
+                    kind.icon(),
+                    name,
+                    kind.name(),
+                    cost,
+                    remaining_money
+                )?;
+            }
+            GameEvent::AnimalsFed {
+                fed,
+                feed_used,
+                hungry,
+            } => {
+                let used: Vec<String> = feed_used
+                    .iter()
+                    .map(|(feed, count)| format!("{} x{}", feed, count))
+                    .collect();
+                write!(
+                    stdout,
+                    "🌾 Fed {} animal(s) with {}.\r\n",
+                    fed,
+                    used.join(", ")
+                )?;
+                if !hungry.is_empty() {
+                    write!(
+                        stdout,
+                        "😟 Ran out of feed! Still hungry: {}\r\n",
+                        hungry.join(", ")
+                    )?;
+                }
+            }
+            GameEvent::AnimalsTended { products, hungry } => {
+                for (product, count) in products {
+                    write!(
+                        stdout,
+                        "🥚 Your animals left you {} x{}\r\n",
+                        product, count
+                    )?;
+                }
+                if !hungry.is_empty() {
+                    write!(
+                        stdout,
+                        "😢 These animals went to bed hungry: {}\r\n",
+                        hungry.join(", ")
+                    )?;
+                }
+            }
+        }
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    /// Draw the farm as a grid: free plots show their number, planted plots their crop
+    pub fn render_farm(stdout: &mut impl Write, info: &GameInfo) -> Result<()> {
+        let fields = &info.fields;
+        let map = plot_map(fields);
+
+        write!(stdout, "🗺️  Farm:\r\n")?;
+        for y in 0..FARM_HEIGHT {
+            write!(stdout, "   ")?;
+            for x in 0..FARM_WIDTH {
+                let Some(plot) = plot_at(x, y) else {
+                    continue;
+                };
+
+                let sprinkler = info.sprinklers.iter().find(|s| s.plot == plot);
+                let machine = info.machines.iter().find(|m| m.plot == plot);
+
+                if let Some(sprinkler) = sprinkler {
+                    write!(stdout, "{} ", sprinkler.kind.icon())?;
+                } else if let Some(machine) = machine {
+                    write!(stdout, "{} ", machine.kind.icon())?;
+                } else {
+                    match map[plot].map(|index| &fields[index]) {
+                        Some(crop) if crop.ready_harvest => write!(stdout, "{} ", crop.icon)?,
+                        Some(_) => write!(stdout, "🌱 ")?,
+                        None => write!(stdout, "{:02} ", plot + 1)?,
+                    }
+                }
+            }
+            write!(stdout, "\r\n")?;
+        }
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    pub fn render_machines(stdout: &mut impl Write, info: &GameInfo) -> Result<()> {
+        for machine in &info.machines {
+            write!(
+                stdout,
+                "{} {} (plot {}): ",
+                machine.kind.icon(),
+                machine.kind.name(),
+                machine.plot + 1
+            )?;
+
+            match &machine.processing {
+                Some(processing) if processing.ready => {
+                    write!(stdout, "{} ready to collect\r\n", processing.output)?
+                }
+                Some(processing) => write!(
+                    stdout,
+                    "making {} (ready on day {})\r\n",
+                    processing.output, processing.ready_day
+                )?,
+                None => write!(stdout, "idle\r\n")?,
+            }
+        }
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    pub fn render_animals(stdout: &mut impl Write, info: &GameInfo) -> Result<()> {
+        for building in [BuildingKind::Coop, BuildingKind::Barn] {
+            let count = info.buildings.iter().filter(|b| **b == building).count();
+            if count > 0 {
+                write!(
+                    stdout,
+                    "{} {} x{}\r\n",
+                    building.icon(),
+                    building.name(),
+                    count
+                )?;
+            }
+        }
+
+        for animal in &info.animals {
+            write!(
+                stdout,
+                "{} {} the {} (happiness {}%{})\r\n",
+                animal.kind.icon(),
+                animal.name,
+                animal.kind.name(),
+                animal.happiness,
+                if animal.fed_today {
+                    ", fed"
+                } else {
+                    ", hungry"
+                }
+            )?;
+        }
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    /// Draw the fertility of every plot as a grid
+    pub fn render_soil(stdout: &mut impl Write, plots: &[Plot]) -> Result<()> {
+        write!(stdout, "🟫 Soil fertility (%):\r\n")?;
+        for y in 0..FARM_HEIGHT {
+            write!(stdout, "   ")?;
+            for x in 0..FARM_WIDTH {
+                let Some(soil) = plot_at(x, y).and_then(|plot| plots.get(plot)) else {
+                    continue;
+                };
+
+                write!(stdout, "{:3} ", soil.fertility)?;
+            }
+            write!(stdout, "\r\n")?;
+        }
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    pub fn render_season_warning(stdout: &mut impl Write, warning: &SeasonWarning) -> Result<()> {
+        write!(
+            stdout,
+            "⚠️  {} day(s) until {}! These crops won't mature in time:\r\n",
+            warning.days_until_season_end, warning.next_season
+        )?;
+        for crop_name in &warning.crops_at_risk {
+            write!(stdout, "      - {}\r\n", crop_name)?;
         }
 
         stdout.flush()?;
         Ok(())
     }
 
-    pub fn render_season_change(stdout: &mut StdoutLock, change: &SeasonChangeEvent) -> Result<()> {
+    pub fn render_festival(stdout: &mut impl Write, festival: &Festival) -> Result<()> {
+        write!(stdout, "\r\n")?;
+        write!(
+            stdout,
+            "{} Today is the {}! {}\r\n",
+            festival.icon, festival.name, festival.icon
+        )?;
+        write!(stdout, "   {}\r\n", festival.description)?;
+
+        if !festival.special_seeds.is_empty() {
+            write!(stdout, "   🏪 Special seeds in the shop:\r\n")?;
+            for crop in &festival.special_seeds {
+                write!(stdout, "      - {} {}\r\n", crop.icon, crop.name)?;
+            }
+        }
+
+        if festival.crop_contest {
+            write!(
+                stdout,
+                "   🏆 Enter a fully grown crop in the judging contest with 'contest'!\r\n"
+            )?;
+        }
+
+        write!(stdout, "\r\n")?;
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    pub fn render_season_change(stdout: &mut impl Write, change: &SeasonChangeEvent) -> Result<()> {
         write!(stdout, "\r\n")?;
         write!(stdout, "🎉 ═══════════════════════════════════ 🎉\r\n")?;
         write!(stdout, "   Season Changed!\r\n")?;
@@ -114,18 +683,46 @@ impl GameRenderer {
         Ok(())
     }
 
-    pub fn render_status(stdout: &mut StdoutLock, info: &GameInfo) -> Result<()> {
+    pub fn render_status(stdout: &mut impl Write, info: &GameInfo) -> Result<()> {
         write!(stdout, "📊 Player Status:\r\n")?;
         write!(stdout, "👤 Name: {}\r\n", info.player_name)?;
         write!(
             stdout,
-            "🗓️  Day: {} (Year: {}, {} Day {})\r\n",
+            "🗓️  Day: {} (Year: {}, {} Day {}/{})\r\n",
             info.day,
             info.year,
             info.current_season.name(),
-            info.day_in_season
+            info.day_in_season,
+            info.calendar.days_per_season
         )?;
         write!(stdout, "🌍 Season: {}\r\n", info.current_season)?;
+        write!(
+            stdout,
+            "⏳ {} day(s) until {}\r\n",
+            info.days_until_season_end, info.next_season
+        )?;
+        if !info.crops_at_risk.is_empty() {
+            write!(
+                stdout,
+                "⚠️  Won't mature before the season ends: {}\r\n",
+                info.crops_at_risk.join(", ")
+            )?;
+        }
+        write!(
+            stdout,
+            "🕒 Clock: {:02}:{:02} (Shop {})\r\n",
+            info.hour % 24,
+            info.minute,
+            if info.shop_open { "open" } else { "closed" }
+        )?;
+        if let Some(festival) = &info.festival {
+            write!(stdout, "{} Festival: {}\r\n", festival.icon, festival.name)?;
+        }
+        write!(stdout, "⏱️  Time mode: {}", info.time_mode.name())?;
+        if info.time_mode == TimeMode::RealTime {
+            write!(stdout, " ({} minutes per day)", info.minutes_per_day)?;
+        }
+        write!(stdout, "\r\n")?;
 
         Self::draw_status_bar(
             stdout,
@@ -137,6 +734,22 @@ impl GameRenderer {
             Color::Red,
         )?;
         write!(stdout, "🪙 Money: {}\r\n", info.player_money)?;
+        match info.next_level_xp {
+            Some(next_level_xp) => write!(
+                stdout,
+                "🧑‍🌾 Farming level {} (XP {}/{})",
+                info.farming_level, info.farming_xp, next_level_xp
+            )?,
+            None => write!(
+                stdout,
+                "🧑‍🌾 Farming level {} (max, XP {})",
+                info.farming_level, info.farming_xp
+            )?,
+        }
+        if info.energy_discount > 0 {
+            write!(stdout, " · tasks cost {} less energy", info.energy_discount)?;
+        }
+        write!(stdout, "\r\n")?;
         writeln!(stdout)?;
 
         write!(stdout, "📦 Inventory:\r\n")?;
@@ -146,11 +759,31 @@ impl GameRenderer {
             for crop in &info.inventory {
                 write!(
                     stdout,
-                    "- {} {} (Growth Days: {}, Sell Price: {})\r\n",
+                    "- {} {} (Growth Days: {}, Sell Price: {})",
                     crop.icon, crop.name, crop.growth_days, crop.sell_price
                 )?;
+                if let Some(regrow_days) = crop.regrow_days {
+                    write!(stdout, " 🔁 regrows every {} days", regrow_days)?;
+                }
+                write!(stdout, "\r\n")?;
             }
         }
+        for (item_name, count) in &info.items {
+            write!(stdout, "- {} x{}\r\n", item_name, count)?;
+        }
+        for job in &info.crafting {
+            write!(
+                stdout,
+                "- 🔨 {} (ready on day {})\r\n",
+                job.output, job.ready_day
+            )?;
+        }
+        writeln!(stdout)?;
+
+        Self::render_farm(stdout, info)?;
+        Self::render_machines(stdout, info)?;
+        Self::render_animals(stdout, info)?;
+        Self::render_soil(stdout, &info.plots)?;
         writeln!(stdout)?;
 
         write!(stdout, "🌾 Planted Crops:\r\n")?;
@@ -162,16 +795,16 @@ impl GameRenderer {
                     stdout,
                     &format!("{} {}", crop.icon, crop.name),
                     if crop.ready_harvest {
-                        "Ready to harvest".to_string()
+                        format!("Ready to harvest {}", crop.quality.icon())
                     } else {
                         format!(
                             "Watered: {}/{} days",
                             crop.watered_days.len(),
-                            crop.growth_days
+                            crop.days_to_mature()
                         )
                     },
                     crop.watered_days.len() as u8,
-                    crop.growth_days,
+                    crop.days_to_mature(),
                     Color::Green,
                     Color::Blue,
                 )?;
@@ -183,7 +816,7 @@ impl GameRenderer {
     }
 
     fn draw_status_bar(
-        stdout: &mut StdoutLock,
+        stdout: &mut impl Write,
         label: &str,
         suffix_label: String,
         value: u8,
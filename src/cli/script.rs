@@ -0,0 +1,520 @@
+use anyhow::{Context, Result, bail};
+use std::io::{BufRead, ErrorKind, Write};
+
+use crate::core::{
+    GameEngine,
+    animal::{AnimalKind, BuildingKind},
+    crafting::recipe_catalog,
+    farm::plot_map,
+    types::{GameCommand, GameResult, TimeMode},
+};
+
+use super::renderer::GameRenderer;
+
+/// Most times one line can repeat a command, e.g. `buy carrot 999`
+const MAX_REPEAT: u32 = 999;
+
+/// Example of every command, shown by `help`
+pub const USAGE: &[&str] = &[
+    "plant <crop> [count] [at <plot>]   plant all",
+    "water   harvest   gather   sleep   advance",
+    "buy <seed|item> [count]",
+    "sell <item> [count|all]",
+    "craft <recipe> [count]",
+    "fertilize <plot> [fertilizer]",
+    "sprinkler <plot> [kind]   unsprinkler <plot>",
+    "machine <plot> <kind>   unmachine <plot>   load <plot> <item>   collect",
+    "build <coop|barn>   animal <chicken|cow> [name]   feed",
+    "contest <plot>",
+    "mode <real-time|turn-based|paused> [minutes per day]",
+];
+
+/// Parse a command line into the game commands it stands for.
+///
+/// Names are resolved against the current game, so batches such as `plant all`
+/// expand to one command per seed. Blank lines and `#` comments parse to nothing.
+pub fn parse(line: &str, engine: &GameEngine) -> Result<Vec<GameCommand>> {
+    let line = line.split('#').next().unwrap_or_default().trim();
+    let mut words = line.split_whitespace();
+
+    let Some(verb) = words.next() else {
+        return Ok(Vec::new());
+    };
+    let args: Vec<&str> = words.collect();
+
+    let commands = match verb.to_lowercase().as_str() {
+        "plant" | "p" => parse_plant(&args, engine)?,
+        "water" | "w" => vec![GameCommand::WaterCrops],
+        "harvest" | "h" => vec![GameCommand::HarvestCrops],
+        "gather" | "g" => vec![GameCommand::GatherCrops],
+        "sleep" | "s" => vec![GameCommand::Sleep],
+        "advance" => vec![GameCommand::AdvanceDay],
+        "buy" | "b" => parse_buy(&args, engine)?,
+        "sell" | "l" => {
+            let (name, count) = split_count(&args);
+            let item_name = find_name(
+                &name,
+                engine.get_player().items.keys().cloned(),
+                "in storage",
+            )?;
+            let quantity = match count {
+                Count::All => engine.get_player().item_count(&item_name),
+                Count::Exactly(count) => count,
+            };
+            vec![GameCommand::SellItem {
+                item_name,
+                quantity,
+            }]
+        }
+        "craft" | "r" => {
+            let (name, count) = split_count(&args);
+            let recipe = recipe_catalog()
+                .into_iter()
+                .find(|recipe| {
+                    recipe.id.eq_ignore_ascii_case(&name.replace(' ', "_"))
+                        || recipe.name.eq_ignore_ascii_case(&name)
+                })
+                .with_context(|| format!("Unknown recipe: {}", name))?;
+            repeat(
+                GameCommand::Craft {
+                    recipe_id: recipe.id,
+                },
+                count.single()?,
+            )?
+        }
+        "fertilize" | "f" => {
+            let (plot, rest) = split_plot(&args)?;
+            let name = if rest.is_empty() {
+                "Basic Fertilizer".to_string()
+            } else {
+                rest.join(" ")
+            };
+            let item_name = find_name(
+                &name,
+                engine.shop_items().into_iter().map(|(name, _)| name),
+                "in the shop",
+            )?;
+            vec![GameCommand::ApplyFertilizer { plot, item_name }]
+        }
+        "sprinkler" | "k" => {
+            let (plot, rest) = split_plot(&args)?;
+            let name = if rest.is_empty() {
+                "Sprinkler".to_string()
+            } else {
+                rest.join(" ")
+            };
+            let item_name = find_name(
+                &name,
+                engine.shop_items().into_iter().map(|(name, _)| name),
+                "in the shop",
+            )?;
+            vec![GameCommand::PlaceSprinkler { plot, item_name }]
+        }
+        "unsprinkler" => vec![GameCommand::RemoveSprinkler {
+            plot: split_plot(&args)?.0,
+        }],
+        "machine" | "m" => {
+            let (plot, rest) = split_plot(&args)?;
+            let item_name = find_name(
+                &rest.join(" "),
+                engine.shop_items().into_iter().map(|(name, _)| name),
+                "in the shop",
+            )?;
+            vec![GameCommand::PlaceMachine { plot, item_name }]
+        }
+        "unmachine" => vec![GameCommand::RemoveMachine {
+            plot: split_plot(&args)?.0,
+        }],
+        "load" => {
+            let (plot, rest) = split_plot(&args)?;
+            let item_name = find_name(
+                &rest.join(" "),
+                engine.get_player().items.keys().cloned(),
+                "in storage",
+            )?;
+            vec![GameCommand::LoadMachine { plot, item_name }]
+        }
+        "collect" => vec![GameCommand::CollectMachineProducts],
+        "build" => {
+            let building = match args.join(" ").to_lowercase().as_str() {
+                "coop" => BuildingKind::Coop,
+                "barn" => BuildingKind::Barn,
+                other => bail!("Unknown building: {}", other),
+            };
+            vec![GameCommand::Build { building }]
+        }
+        "animal" => {
+            let (kind, name) = args.split_first().context("Which animal? chicken or cow")?;
+            let kind = match kind.to_lowercase().as_str() {
+                "chicken" => AnimalKind::Chicken,
+                "cow" => AnimalKind::Cow,
+                other => bail!("Unknown animal: {}", other),
+            };
+            vec![GameCommand::BuyAnimal {
+                kind,
+                name: name.join(" "),
+            }]
+        }
+        "feed" => vec![GameCommand::FeedAnimals],
+        "contest" | "c" => {
+            let (plot, _) = split_plot(&args)?;
+            let field_index = plot_map(&engine.get_player().fields)
+                .get(plot)
+                .copied()
+                .flatten()
+                .with_context(|| format!("There is no crop in plot {}", plot + 1))?;
+            vec![GameCommand::EnterCropContest { field_index }]
+        }
+        "mode" => {
+            let mode = match args.first().map(|mode| mode.to_lowercase()).as_deref() {
+                Some("real-time") | Some("realtime") => TimeMode::RealTime,
+                Some("turn-based") | Some("turnbased") => TimeMode::TurnBased,
+                Some("paused") => TimeMode::Paused,
+                _ => bail!("Time mode must be real-time, turn-based or paused"),
+            };
+            let minutes_per_day = args
+                .get(1)
+                .map(|minutes| minutes.parse::<i64>())
+                .transpose()
+                .context("Minutes per day must be a number")?;
+            vec![GameCommand::SetTimeMode {
+                mode,
+                minutes_per_day,
+            }]
+        }
+        other => bail!("Unknown command: {}", other),
+    };
+
+    Ok(commands)
+}
+
+/// Parse and execute a command line, rendering each event.
+///
+/// Batches stop at the first command that fails. Returns whether every command succeeded.
+pub fn execute_line(engine: &mut GameEngine, line: &str, out: &mut impl Write) -> Result<bool> {
+    let commands = match parse(line, engine) {
+        Ok(commands) => commands,
+        Err(err) => {
+            write!(out, "😖 {}\r\n", err)?;
+            return Ok(false);
+        }
+    };
+
+    for command in commands {
+        match engine.execute(command) {
+            GameResult::Success(event) => GameRenderer::render_event(out, &event)?,
+            GameResult::Error(err) => {
+                write!(out, "😖 {}\r\n", err)?;
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+/// Run every line of a script, returning how many lines failed
+pub fn run_script(engine: &mut GameEngine, script: &str, out: &mut impl Write) -> Result<usize> {
+    let mut failures = 0;
+
+    for (number, line) in script.lines().enumerate() {
+        if parse(line, engine).is_ok_and(|commands| commands.is_empty()) {
+            continue;
+        }
+
+        write!(out, "> {}\r\n", line.trim())?;
+        if !execute_line(engine, line, out)? {
+            write!(out, "   (line {})\r\n", number + 1)?;
+            failures += 1;
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Execute `GameCommand` JSON lines, writing one `GameResult` JSON line for each.
+///
+/// Lines that aren't valid commands or text get an error result, like the WASM wrapper's
+/// `executeCommand`. Returns how many commands failed.
+pub fn execute_json_lines(
+    engine: &mut GameEngine,
+    input: impl BufRead,
+    out: &mut impl Write,
+) -> Result<usize> {
+    let mut failures = 0;
+
+    for line in input.lines() {
+        let result = match line {
+            Ok(line) if line.trim().is_empty() => continue,
+            Ok(line) => match serde_json::from_str::<GameCommand>(&line) {
+                Ok(command) => engine.execute(command),
+                Err(err) => GameResult::Error(format!("Invalid command: {}", err)),
+            },
+            // e.g. a line that isn't UTF-8; the input can't be read past other errors
+            Err(err) if err.kind() == ErrorKind::InvalidData => {
+                GameResult::Error(format!("Failed to read command: {}", err))
+            }
+            Err(err) => return Err(err).context("Failed to read command"),
+        };
+        if matches!(result, GameResult::Error(_)) {
+            failures += 1;
+        }
+
+        serde_json::to_writer(&mut *out, &result).context("Failed to serialize result")?;
+        writeln!(out)?;
+        out.flush()?;
+    }
+
+    Ok(failures)
+}
+
+enum Count {
+    Exactly(u32),
+    All,
+}
+
+impl Count {
+    fn single(self) -> Result<u32> {
+        match self {
+            Count::Exactly(count) => Ok(count),
+            Count::All => bail!("'all' can't be used here"),
+        }
+    }
+}
+
+/// Split a trailing count ("carrot 3", "egg all") off a name, defaulting to one
+fn split_count(args: &[&str]) -> (String, Count) {
+    match args.split_last() {
+        Some((last, name)) if last.eq_ignore_ascii_case("all") => (name.join(" "), Count::All),
+        Some((last, name)) => match last.parse::<u32>() {
+            Ok(count) => (name.join(" "), Count::Exactly(count)),
+            Err(_) => (args.join(" "), Count::Exactly(1)),
+        },
+        None => (String::new(), Count::Exactly(1)),
+    }
+}
+
+/// Split a leading 1-based plot number off the arguments, returning its index
+fn split_plot<'a>(args: &'a [&'a str]) -> Result<(usize, &'a [&'a str])> {
+    let (plot, rest) = args.split_first().context("Which plot?")?;
+
+    match plot.parse::<usize>() {
+        Ok(plot) if plot > 0 => Ok((plot - 1, rest)),
+        _ => bail!("Invalid plot: {}", plot),
+    }
+}
+
+/// Case-insensitive lookup of a name among the candidates, returning its proper spelling
+fn find_name(name: &str, candidates: impl Iterator<Item = String>, place: &str) -> Result<String> {
+    if name.is_empty() {
+        bail!("Which item?");
+    }
+
+    candidates
+        .into_iter()
+        .find(|candidate| candidate.eq_ignore_ascii_case(name))
+        .with_context(|| format!("No {} {}", name, place))
+}
+
+/// Queue a command `count` times, refusing counts too big to be meant
+fn repeat(command: GameCommand, count: u32) -> Result<Vec<GameCommand>> {
+    if count > MAX_REPEAT {
+        bail!("At most {} at a time", MAX_REPEAT);
+    }
+
+    Ok((0..count).map(|_| command.clone()).collect())
+}
+
+fn parse_plant(args: &[&str], engine: &GameEngine) -> Result<Vec<GameCommand>> {
+    // Planting removes the seed from the inventory, shifting later indices
+    let mut inventory: Vec<String> = engine
+        .get_player()
+        .inventory
+        .iter()
+        .map(|crop| crop.name.clone())
+        .collect();
+
+    if args.len() == 1 && args[0].eq_ignore_ascii_case("all") {
+        if inventory.is_empty() {
+            bail!("You don't have any seeds");
+        }
+        return repeat(
+            GameCommand::PlantCrop {
+                crop_index: 0,
+                plot: None,
+            },
+            inventory.len() as u32,
+        );
+    }
+
+    let (args, plot) = match args.iter().position(|arg| arg.eq_ignore_ascii_case("at")) {
+        Some(at) => {
+            let (plot, _) = split_plot(&args[at + 1..])?;
+            (&args[..at], Some(plot))
+        }
+        None => (args, None),
+    };
+
+    let (name, count) = split_count(args);
+    let count = count.single()?;
+    if name.is_empty() {
+        bail!("Which crop?");
+    }
+    if plot.is_some() && count > 1 {
+        bail!("Only one crop fits in a plot");
+    }
+
+    let mut commands = Vec::new();
+    for _ in 0..count {
+        let crop_index = inventory
+            .iter()
+            .position(|crop| crop.eq_ignore_ascii_case(&name))
+            .with_context(|| format!("Not enough {} seeds", name))?;
+        inventory.remove(crop_index);
+        commands.push(GameCommand::PlantCrop { crop_index, plot });
+    }
+
+    Ok(commands)
+}
+
+fn parse_buy(args: &[&str], engine: &GameEngine) -> Result<Vec<GameCommand>> {
+    let (name, count) = split_count(args);
+    let count = count.single()?;
+
+    if let Some(seed) = engine
+        .available_seeds()
+        .into_iter()
+        .find(|crop| crop.name.eq_ignore_ascii_case(&name))
+    {
+        return repeat(
+            GameCommand::BuySeed {
+                seed_name: seed.name,
+            },
+            count,
+        );
+    }
+
+    let item_name = find_name(
+        &name,
+        engine.shop_items().into_iter().map(|(name, _)| name),
+        "seeds or items in the shop",
+    )?;
+    repeat(GameCommand::BuyItem { item_name }, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine() -> GameEngine {
+        GameEngine::new_game("Tester")
+    }
+
+    #[test]
+    fn split_count_reads_trailing_counts() {
+        assert!(matches!(
+            split_count(&["carrot", "3"]),
+            (name, Count::Exactly(3)) if name == "carrot"
+        ));
+        assert!(matches!(
+            split_count(&["snow", "pea", "all"]),
+            (name, Count::All) if name == "snow pea"
+        ));
+        assert!(matches!(
+            split_count(&["snow", "pea"]),
+            (name, Count::Exactly(1)) if name == "snow pea"
+        ));
+        assert!(matches!(split_count(&[]), (name, Count::Exactly(1)) if name.is_empty()));
+    }
+
+    #[test]
+    fn parse_skips_blank_lines_and_comments() {
+        let engine = engine();
+
+        assert!(parse("", &engine).unwrap().is_empty());
+        assert!(parse("   # just a note", &engine).unwrap().is_empty());
+        assert!(matches!(
+            parse("water # morning chores", &engine).unwrap().as_slice(),
+            [GameCommand::WaterCrops]
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_commands() {
+        let err = parse("dance", &engine()).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown command: dance");
+    }
+
+    #[test]
+    fn parse_buy_repeats_the_command() {
+        let commands = parse("buy carrot 3", &engine()).unwrap();
+
+        assert_eq!(commands.len(), 3);
+        assert!(commands.iter().all(
+            |command| matches!(command, GameCommand::BuySeed { seed_name } if seed_name == "Carrot")
+        ));
+    }
+
+    #[test]
+    fn parse_buy_caps_the_count() {
+        let line = format!("buy carrot {}", MAX_REPEAT + 1);
+        assert!(parse(&line, &engine()).is_err());
+    }
+
+    #[test]
+    fn parse_plant_tracks_shifting_inventory_indices() {
+        let mut engine = engine();
+        let carrot = engine.get_player().inventory[0].clone();
+        assert_eq!(carrot.name, "Carrot");
+        engine.get_player_mut().inventory.push(carrot);
+        let last = engine.get_player().inventory.len() - 1;
+
+        // Planting the first carrot moves the second one down a place
+        let indices: Vec<usize> = parse_plant(&["carrot", "2"], &engine)
+            .unwrap()
+            .into_iter()
+            .map(|command| match command {
+                GameCommand::PlantCrop { crop_index, .. } => crop_index,
+                other => panic!("unexpected command {:?}", other),
+            })
+            .collect();
+        assert_eq!(indices, [0, last - 1]);
+    }
+
+    #[test]
+    fn parse_plant_reads_the_plot() {
+        assert!(matches!(
+            parse_plant(&["carrot", "at", "4"], &engine())
+                .unwrap()
+                .as_slice(),
+            [GameCommand::PlantCrop {
+                crop_index: 0,
+                plot: Some(3)
+            }]
+        ));
+    }
+
+    #[test]
+    fn parse_plant_all_plants_every_seed() {
+        let engine = engine();
+        let commands = parse_plant(&["all"], &engine).unwrap();
+
+        assert_eq!(commands.len(), engine.get_player().inventory.len());
+        assert!(commands.iter().all(|command| matches!(
+            command,
+            GameCommand::PlantCrop {
+                crop_index: 0,
+                plot: None
+            }
+        )));
+    }
+
+    #[test]
+    fn parse_plant_refuses_missing_seeds() {
+        let engine = engine();
+
+        assert!(parse_plant(&["starfruit"], &engine).is_err());
+        assert!(parse_plant(&["carrot", "2", "at", "1"], &engine).is_err());
+        assert!(parse_plant(&[], &engine).is_err());
+    }
+}
@@ -0,0 +1,636 @@
+use anyhow::Result;
+use crossterm::{
+    ExecutableCommand, QueueableCommand, cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, mpsc};
+
+use crate::core::{
+    GameEngine,
+    farm::{FARM_HEIGHT, FARM_WIDTH, plot_at, plot_coords, plot_map},
+    types::{GameCommand, GameEvent, GameInfo, GameResult},
+};
+
+use super::{input::RawMode, persistence::GamePersistence, renderer::GameRenderer};
+
+/// Lines kept in the event log
+const LOG_LIMIT: usize = 200;
+/// How often the screen is redrawn while idle, so the clock keeps ticking
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Alternate screen in raw mode with the cursor hidden, restored when dropped
+/// so a panic doesn't leave the terminal unusable
+struct FullScreen {
+    _raw: RawMode,
+}
+
+impl FullScreen {
+    fn enter(stdout: &mut impl Write) -> Result<Self> {
+        let raw = RawMode::enable()?;
+        stdout.execute(EnterAlternateScreen)?;
+        stdout.execute(cursor::Hide)?;
+
+        Ok(Self { _raw: raw })
+    }
+}
+
+impl Drop for FullScreen {
+    fn drop(&mut self) {
+        let mut stdout = io::stdout();
+        stdout.execute(cursor::Show).ok();
+        stdout.execute(LeaveAlternateScreen).ok();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Panel {
+    Field,
+    Inventory,
+    Log,
+    Inbox,
+}
+
+impl Panel {
+    fn next(self) -> Self {
+        match self {
+            Panel::Field => Panel::Inventory,
+            Panel::Inventory => Panel::Log,
+            Panel::Log => Panel::Inbox,
+            Panel::Inbox => Panel::Field,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            Panel::Field => Panel::Inbox,
+            Panel::Inventory => Panel::Field,
+            Panel::Log => Panel::Inventory,
+            Panel::Inbox => Panel::Log,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+}
+
+/// What an inventory row refers to
+enum Entry {
+    Crop(usize),
+    Item(String),
+}
+
+/// Full-screen terminal UI sharing the game engine with the day monitor and trade node
+pub struct Tui {
+    game_engine: Arc<Mutex<GameEngine>>,
+    day_events: mpsc::UnboundedReceiver<GameEvent>,
+    trade_events: Option<mpsc::UnboundedReceiver<String>>,
+    endpoint_id: Option<String>,
+    focus: Panel,
+    /// Plot under the field cursor
+    cursor: usize,
+    /// Selected row of the inventory panel
+    selected: usize,
+    log: Vec<String>,
+    /// Lines scrolled back from the newest log entry
+    log_scroll: usize,
+    inbox: Vec<String>,
+}
+
+impl Tui {
+    pub fn new(
+        game_engine: Arc<Mutex<GameEngine>>,
+        day_events: mpsc::UnboundedReceiver<GameEvent>,
+        trade_events: Option<mpsc::UnboundedReceiver<String>>,
+        endpoint_id: Option<String>,
+    ) -> Self {
+        Self {
+            game_engine,
+            day_events,
+            trade_events,
+            endpoint_id,
+            focus: Panel::Field,
+            cursor: 0,
+            selected: 0,
+            log: vec!["🌱 Welcome to the P2P Harvest Game!".to_string()],
+            log_scroll: 0,
+            inbox: Vec::new(),
+        }
+    }
+
+    /// Take over the terminal until the player quits
+    pub fn run(&mut self) -> Result<()> {
+        let mut stdout = io::stdout();
+        let _screen = FullScreen::enter(&mut stdout)?;
+
+        self.event_loop(&mut stdout)
+    }
+
+    fn event_loop(&mut self, stdout: &mut impl Write) -> Result<()> {
+        let mut last_draw: Option<Instant> = None;
+
+        loop {
+            let mut dirty = last_draw.is_none_or(|drawn| drawn.elapsed() >= REFRESH_INTERVAL);
+
+            while let Ok(event) = self.day_events.try_recv() {
+                self.log_event(&event)?;
+                dirty = true;
+            }
+
+            let mut traded = false;
+            if let Some(trade_events) = &mut self.trade_events {
+                while let Ok(summary) = trade_events.try_recv() {
+                    self.inbox.push(summary);
+                    traded = true;
+                }
+            }
+            if traded {
+                GamePersistence::save(&self.game_engine.blocking_lock())?;
+                dirty = true;
+            }
+
+            if dirty {
+                self.draw(stdout)?;
+                last_draw = Some(Instant::now());
+            }
+
+            if !event::poll(Duration::from_millis(100))? {
+                continue;
+            }
+
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    if !self.handle_key(key)? {
+                        return Ok(());
+                    }
+                    last_draw = None;
+                }
+                Event::Resize(..) => last_draw = None,
+                _ => {}
+            }
+        }
+    }
+
+    /// Handle a key press, returning `false` when the player quits
+    fn handle_key(&mut self, key: KeyEvent) -> Result<bool> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Ok(false);
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Tab => self.focus = self.focus.next(),
+            KeyCode::BackTab => self.focus = self.focus.previous(),
+            KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right => self.navigate(key.code),
+            KeyCode::Enter => self.activate()?,
+            KeyCode::Char('p') => self.plant_selected()?,
+            KeyCode::Char('w') => self.execute(GameCommand::WaterCrops)?,
+            KeyCode::Char('h') => self.execute(GameCommand::HarvestCrops)?,
+            KeyCode::Char('g') => self.execute(GameCommand::GatherCrops)?,
+            KeyCode::Char('s') => self.execute(GameCommand::Sleep)?,
+            KeyCode::Char('c') => self.enter_contest()?,
+            _ => {}
+        }
+
+        Ok(true)
+    }
+
+    fn navigate(&mut self, code: KeyCode) {
+        match self.focus {
+            Panel::Field => {
+                let (x, y) = plot_coords(self.cursor);
+                let (x, y) = match code {
+                    KeyCode::Up => (x, y.saturating_sub(1)),
+                    KeyCode::Down => (x, (y + 1).min(FARM_HEIGHT - 1)),
+                    KeyCode::Left => (x.saturating_sub(1), y),
+                    _ => ((x + 1).min(FARM_WIDTH - 1), y),
+                };
+                self.cursor = plot_at(x, y).unwrap_or(self.cursor);
+            }
+            Panel::Inventory => {
+                let entries = self.entries(&self.info()).len();
+                self.selected = match code {
+                    KeyCode::Up => self.selected.saturating_sub(1),
+                    KeyCode::Down => (self.selected + 1).min(entries.saturating_sub(1)),
+                    _ => self.selected,
+                };
+            }
+            Panel::Log => {
+                self.log_scroll = match code {
+                    KeyCode::Up => (self.log_scroll + 1).min(self.log.len().saturating_sub(1)),
+                    KeyCode::Down => self.log_scroll.saturating_sub(1),
+                    _ => self.log_scroll,
+                };
+            }
+            Panel::Inbox => {}
+        }
+    }
+
+    /// Enter plants the selected crop at the cursor, or sells one of the selected item
+    fn activate(&mut self) -> Result<()> {
+        match self.entries(&self.info()).into_iter().nth(self.selected) {
+            Some(Entry::Crop(_)) => self.plant_selected(),
+            Some(Entry::Item(item_name)) if self.focus == Panel::Inventory => {
+                self.execute(GameCommand::SellItem {
+                    item_name,
+                    quantity: 1,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn plant_selected(&mut self) -> Result<()> {
+        match self.entries(&self.info()).into_iter().nth(self.selected) {
+            Some(Entry::Crop(crop_index)) => self.execute(GameCommand::PlantCrop {
+                crop_index,
+                plot: Some(self.cursor),
+            }),
+            _ => {
+                self.push_log("😖 Select a seed in the inventory first".to_string());
+                Ok(())
+            }
+        }
+    }
+
+    fn enter_contest(&mut self) -> Result<()> {
+        let info = self.info();
+
+        match plot_map(&info.fields)[self.cursor] {
+            Some(field_index) => self.execute(GameCommand::EnterCropContest { field_index }),
+            None => {
+                self.push_log("😖 Move the cursor onto a crop to enter it".to_string());
+                Ok(())
+            }
+        }
+    }
+
+    fn execute(&mut self, command: GameCommand) -> Result<()> {
+        let result = {
+            let mut engine = self.game_engine.blocking_lock();
+            let result = engine.execute(command);
+
+            if matches!(result, GameResult::Success(_)) {
+                GamePersistence::save(&engine)?;
+            }
+
+            result
+        };
+
+        match result {
+            GameResult::Success(event) => self.log_event(&event)?,
+            GameResult::Error(err) => self.push_log(format!("😖 {}", err)),
+        }
+
+        let entries = self.entries(&self.info()).len();
+        self.selected = self.selected.min(entries.saturating_sub(1));
+
+        Ok(())
+    }
+
+    fn info(&self) -> GameInfo {
+        self.game_engine.blocking_lock().get_info()
+    }
+
+    /// Inventory rows: seeds first, then other items
+    fn entries(&self, info: &GameInfo) -> Vec<Entry> {
+        (0..info.inventory.len())
+            .map(Entry::Crop)
+            .chain(info.items.keys().cloned().map(Entry::Item))
+            .collect()
+    }
+
+    fn log_event(&mut self, event: &GameEvent) -> Result<()> {
+        let mut buffer = Vec::new();
+        GameRenderer::render_event(&mut buffer, event)?;
+
+        for line in String::from_utf8_lossy(&buffer).lines() {
+            let line = line.trim();
+            if !line.is_empty() {
+                self.push_log(line.to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_log(&mut self, line: String) {
+        self.log.push(line);
+        self.log_scroll = 0;
+
+        if self.log.len() > LOG_LIMIT {
+            self.log.remove(0);
+        }
+    }
+
+    fn draw(&self, stdout: &mut impl Write) -> Result<()> {
+        let info = self.info();
+        let (width, height) = terminal::size()?;
+        let half = width / 2;
+        let field_height = FARM_HEIGHT as u16 + 5;
+        let bottom_y = 4 + field_height;
+        let bottom_height = height.saturating_sub(bottom_y + 1).max(3);
+
+        stdout.queue(Clear(ClearType::All))?;
+
+        let status = Rect {
+            x: 0,
+            y: 0,
+            width,
+            height: 4,
+        };
+        self.draw_panel(stdout, status, "Status", false, &status_lines(&info))?;
+
+        let field = Rect {
+            x: 0,
+            y: 4,
+            width: half,
+            height: field_height,
+        };
+        self.draw_panel(stdout, field, "Field", self.focus == Panel::Field, &[])?;
+        self.draw_field(stdout, field, &info)?;
+
+        let inventory = Rect {
+            x: half,
+            y: 4,
+            width: width - half,
+            height: field_height,
+        };
+        let lines = self.inventory_lines(&info);
+        let visible = (inventory.height as usize).saturating_sub(2);
+        let first = (self.selected + 1).saturating_sub(visible);
+        self.draw_panel(
+            stdout,
+            inventory,
+            "Inventory",
+            self.focus == Panel::Inventory,
+            &lines[first.min(lines.len())..],
+        )?;
+
+        let log = Rect {
+            x: 0,
+            y: bottom_y,
+            width: width * 2 / 3,
+            height: bottom_height,
+        };
+        let visible = (log.height as usize).saturating_sub(2);
+        let end = self.log.len().saturating_sub(self.log_scroll);
+        let start = end.saturating_sub(visible);
+        self.draw_panel(
+            stdout,
+            log,
+            "Event log",
+            self.focus == Panel::Log,
+            &self.log[start..end],
+        )?;
+
+        let inbox = Rect {
+            x: log.width,
+            y: bottom_y,
+            width: width - log.width,
+            height: bottom_height,
+        };
+        let mut lines = match (&self.trade_events, &self.endpoint_id) {
+            (Some(_), Some(id)) => vec![format!("📋 {}", id)],
+            _ => vec!["Trading unavailable".to_string()],
+        };
+        let visible = (inbox.height as usize).saturating_sub(3);
+        lines.extend(
+            self.inbox[self.inbox.len().saturating_sub(visible)..]
+                .iter()
+                .cloned(),
+        );
+        self.draw_panel(
+            stdout,
+            inbox,
+            "Trade inbox",
+            self.focus == Panel::Inbox,
+            &lines,
+        )?;
+
+        stdout.queue(cursor::MoveTo(0, height.saturating_sub(1)))?;
+        stdout.queue(SetForegroundColor(Color::DarkGrey))?;
+        stdout.queue(Print(truncate(
+            "Tab panel · ←↑↓→ move · Enter plant/sell · p plant · w water · h harvest · g gather · s sleep · c contest · q quit",
+            width as usize,
+        )))?;
+        stdout.queue(ResetColor)?;
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    fn draw_field(&self, stdout: &mut impl Write, area: Rect, info: &GameInfo) -> Result<()> {
+        let map = plot_map(&info.fields);
+
+        for y in 0..FARM_HEIGHT {
+            stdout.queue(cursor::MoveTo(area.x + 2, area.y + 1 + y as u16))?;
+
+            for x in 0..FARM_WIDTH {
+                let Some(plot) = plot_at(x, y) else {
+                    continue;
+                };
+
+                let cell = if let Some(sprinkler) = info.sprinklers.iter().find(|s| s.plot == plot)
+                {
+                    sprinkler.kind.icon().to_string()
+                } else if let Some(machine) = info.machines.iter().find(|m| m.plot == plot) {
+                    machine.kind.icon().to_string()
+                } else {
+                    match map[plot].map(|index| &info.fields[index]) {
+                        Some(crop) if crop.ready_harvest => crop.icon.clone(),
+                        Some(_) => "🌱".to_string(),
+                        None => format!("{:02}", plot + 1),
+                    }
+                };
+
+                if plot == self.cursor {
+                    stdout.queue(SetAttribute(Attribute::Reverse))?;
+                    stdout.queue(Print(cell))?;
+                    stdout.queue(SetAttribute(Attribute::Reset))?;
+                } else {
+                    stdout.queue(Print(cell))?;
+                }
+                stdout.queue(Print(" "))?;
+            }
+        }
+
+        let plot = info.plots.get(self.cursor).cloned().unwrap_or_default();
+        let mut detail = vec![format!(
+            "Plot {} · fertility {}",
+            self.cursor + 1,
+            plot.fertility
+        )];
+        if let Some(crop) = map[self.cursor].map(|index| &info.fields[index]) {
+            detail.push(format!(
+                "{} {} · watered {}/{}{}",
+                crop.icon,
+                crop.name,
+                crop.watered_days.len(),
+                crop.days_to_mature(),
+                if crop.ready_harvest { " · ready!" } else { "" }
+            ));
+        }
+
+        let inner = area.width.saturating_sub(4) as usize;
+        for (row, line) in detail.iter().enumerate() {
+            stdout.queue(cursor::MoveTo(
+                area.x + 2,
+                area.y + 2 + FARM_HEIGHT as u16 + row as u16,
+            ))?;
+            stdout.queue(Print(truncate(line, inner)))?;
+        }
+
+        Ok(())
+    }
+
+    fn inventory_lines(&self, info: &GameInfo) -> Vec<String> {
+        let engine = self.game_engine.blocking_lock();
+        let rows: Vec<String> = info
+            .inventory
+            .iter()
+            .map(|crop| {
+                format!(
+                    "{} {} seed (⚡{})",
+                    crop.icon,
+                    crop.name,
+                    engine.get_player().energy_cost(crop)
+                )
+            })
+            .chain(
+                info.items
+                    .iter()
+                    .map(|(name, count)| format!("📦 {} x{}", name, count)),
+            )
+            .collect();
+
+        if rows.is_empty() {
+            return vec!["Nothing in storage".to_string()];
+        }
+
+        rows.into_iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let marker = if index == self.selected { "▶" } else { " " };
+                format!("{} {}", marker, row)
+            })
+            .collect()
+    }
+
+    fn draw_panel(
+        &self,
+        stdout: &mut impl Write,
+        area: Rect,
+        title: &str,
+        focused: bool,
+        lines: &[String],
+    ) -> Result<()> {
+        if area.width < 4 || area.height < 2 {
+            return Ok(());
+        }
+
+        let inner = area.width as usize - 2;
+        let color = if focused {
+            Color::Yellow
+        } else {
+            Color::DarkGrey
+        };
+
+        stdout.queue(SetForegroundColor(color))?;
+        let title = truncate(&format!(" {} ", title), inner);
+        stdout.queue(cursor::MoveTo(area.x, area.y))?;
+        stdout.queue(Print(format!(
+            "┌{}{}┐",
+            title,
+            "─".repeat(inner - display_width(&title))
+        )))?;
+        for row in 1..area.height - 1 {
+            stdout.queue(cursor::MoveTo(area.x, area.y + row))?;
+            stdout.queue(Print("│"))?;
+            stdout.queue(cursor::MoveTo(area.x + area.width - 1, area.y + row))?;
+            stdout.queue(Print("│"))?;
+        }
+        stdout.queue(cursor::MoveTo(area.x, area.y + area.height - 1))?;
+        stdout.queue(Print(format!("└{}┘", "─".repeat(inner))))?;
+        stdout.queue(ResetColor)?;
+
+        for (row, line) in lines.iter().take(area.height as usize - 2).enumerate() {
+            stdout.queue(cursor::MoveTo(area.x + 1, area.y + 1 + row as u16))?;
+            stdout.queue(Print(truncate(line, inner)))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn status_lines(info: &GameInfo) -> Vec<String> {
+    let level = match info.next_level_xp {
+        Some(next) => format!(
+            "Lv {} ({}/{} XP)",
+            info.farming_level, info.farming_xp, next
+        ),
+        None => format!("Lv {} (max)", info.farming_level),
+    };
+    let festival = info
+        .festival
+        .as_ref()
+        .map(|festival| format!(" · {} {}", festival.icon, festival.name))
+        .unwrap_or_default();
+
+    vec![
+        format!(
+            "👤 {} · 🪙 {} · 🔋 {}/{} · 🧑‍🌾 {}",
+            info.player_name, info.player_money, info.player_energy, info.max_energy, level
+        ),
+        format!(
+            "📅 Day {} · {} (year {}) · 🕒 {:02}:{:02} · shop {} · {}{}",
+            info.day,
+            info.current_season,
+            info.year,
+            info.hour % 24,
+            info.minute,
+            if info.shop_open { "open" } else { "closed" },
+            info.time_mode.name(),
+            festival
+        ),
+    ]
+}
+
+/// Approximate terminal columns taken by a string: emoji and CJK take two
+fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x200D | 0xFE00..=0xFE0F => 0,
+        0x1100..=0x115F | 0x2E80..=0xA4CF | 0xAC00..=0xD7A3 | 0xF900..=0xFAFF => 2,
+        0x1F000..=0x1FAFF => 2,
+        _ => 1,
+    }
+}
+
+/// Cut a string down to at most `width` terminal columns
+fn truncate(text: &str, width: usize) -> String {
+    let mut used = 0;
+    let mut result = String::new();
+
+    for c in text.chars() {
+        used += char_width(c);
+        if used > width {
+            break;
+        }
+        result.push(c);
+    }
+
+    result
+}
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Animals a building can house
+pub const BUILDING_CAPACITY: usize = 4;
+/// Starting happiness of a newly bought animal
+pub const STARTING_HAPPINESS: u8 = 50;
+/// Happiness gained by sleeping on a full stomach
+pub const FED_HAPPINESS_GAIN: u8 = 10;
+/// Happiness lost by going to bed hungry
+pub const HUNGRY_HAPPINESS_LOSS: u8 = 20;
+/// Happiness from which animals give a double product
+pub const HAPPY_THRESHOLD: u8 = 90;
+/// Items animals can eat, in the order they are used
+pub const FEED_ITEMS: [&str; 3] = ["Hay", "Wheat", "Corn"];
+/// Price of a bale of hay in the shop
+pub const HAY_PRICE: u32 = 20;
+
+/// Buildings that house animals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum BuildingKind {
+    Coop,
+    Barn,
+}
+
+impl BuildingKind {
+    pub fn name(&self) -> &str {
+        match self {
+            BuildingKind::Coop => "Coop",
+            BuildingKind::Barn => "Barn",
+        }
+    }
+
+    pub fn icon(&self) -> &str {
+        match self {
+            BuildingKind::Coop => "🛖",
+            BuildingKind::Barn => "🏚️",
+        }
+    }
+
+    pub fn price(&self) -> u32 {
+        match self {
+            BuildingKind::Coop => 1500,
+            BuildingKind::Barn => 3000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum AnimalKind {
+    Chicken,
+    Cow,
+}
+
+impl AnimalKind {
+    pub fn name(&self) -> &str {
+        match self {
+            AnimalKind::Chicken => "Chicken",
+            AnimalKind::Cow => "Cow",
+        }
+    }
+
+    pub fn icon(&self) -> &str {
+        match self {
+            AnimalKind::Chicken => "🐔",
+            AnimalKind::Cow => "🐄",
+        }
+    }
+
+    pub fn price(&self) -> u32 {
+        match self {
+            AnimalKind::Chicken => 400,
+            AnimalKind::Cow => 1200,
+        }
+    }
+
+    pub fn housing(&self) -> BuildingKind {
+        match self {
+            AnimalKind::Chicken => BuildingKind::Coop,
+            AnimalKind::Cow => BuildingKind::Barn,
+        }
+    }
+
+    /// Item the animal produces every morning after being fed
+    pub fn product(&self) -> &str {
+        match self {
+            AnimalKind::Chicken => "Egg",
+            AnimalKind::Cow => "Milk",
+        }
+    }
+
+    pub fn product_price(&self) -> u32 {
+        match self {
+            AnimalKind::Chicken => 60,
+            AnimalKind::Cow => 150,
+        }
+    }
+}
+
+pub fn animal_catalog() -> Vec<AnimalKind> {
+    vec![AnimalKind::Chicken, AnimalKind::Cow]
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Animal {
+    #[cfg_attr(feature = "wasm", tsify(type = "string"))]
+    pub id: Uuid,
+    pub name: String,
+    pub kind: AnimalKind,
+    /// 0-100; happy animals give more
+    pub happiness: u8,
+    pub fed_today: bool,
+}
+
+impl Animal {
+    pub fn new(name: &str, kind: AnimalKind) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            kind,
+            happiness: STARTING_HAPPINESS,
+            fed_today: false,
+        }
+    }
+
+    /// Start a new day, returning how many products the animal left overnight
+    pub fn wake_up(&mut self) -> u32 {
+        let produced = if self.fed_today {
+            self.happiness = self.happiness.saturating_add(FED_HAPPINESS_GAIN).min(100);
+            if self.happiness >= HAPPY_THRESHOLD {
+                2
+            } else {
+                1
+            }
+        } else {
+            self.happiness = self.happiness.saturating_sub(HUNGRY_HAPPINESS_LOSS);
+            0
+        };
+
+        self.fed_today = false;
+        produced
+    }
+}
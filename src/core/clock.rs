@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// Minutes after midnight when the player wakes up (6:00)
+pub const DAY_START_MINUTES: u32 = 6 * 60;
+/// Minutes after midnight when the player passes out (26:00, i.e. 2:00 the next morning)
+pub const DAY_END_MINUTES: u32 = 26 * 60;
+/// Length of an in-game day in game minutes
+pub const DAY_LENGTH_MINUTES: u32 = DAY_END_MINUTES - DAY_START_MINUTES;
+
+/// Hour the seed shop opens
+pub const SHOP_OPEN_HOUR: u32 = 9;
+/// Hour the seed shop closes
+pub const SHOP_CLOSE_HOUR: u32 = 17;
+
+/// Game minutes spent by each action
+pub const PLANT_MINUTES: u32 = 30;
+pub const WATER_MINUTES_PER_CROP: u32 = 10;
+pub const HARVEST_MINUTES: u32 = 30;
+pub const BUY_SEED_MINUTES: u32 = 10;
+pub const BUY_ITEM_MINUTES: u32 = 10;
+pub const FERTILIZE_MINUTES: u32 = 15;
+pub const SPRINKLER_MINUTES: u32 = 20;
+pub const SELL_ITEM_MINUTES: u32 = 10;
+pub const CRAFT_MINUTES: u32 = 30;
+pub const MACHINE_MINUTES: u32 = 15;
+pub const BUILD_MINUTES: u32 = 60;
+pub const FEED_MINUTES_PER_ANIMAL: u32 = 5;
+
+/// In-game time of day, counted in minutes after midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameClock {
+    pub minutes: u32,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self {
+            minutes: DAY_START_MINUTES,
+        }
+    }
+}
+
+impl GameClock {
+    /// Advance the clock, stopping at the end of the day
+    pub fn advance(&mut self, minutes: u32) {
+        self.minutes = (self.minutes + minutes).min(DAY_END_MINUTES);
+    }
+
+    /// Clock position for a fraction of the day elapsed in real time
+    pub fn from_day_progress(progress: f64) -> Self {
+        let progress = progress.clamp(0.0, 1.0);
+
+        Self {
+            minutes: DAY_START_MINUTES + (DAY_LENGTH_MINUTES as f64 * progress) as u32,
+        }
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.minutes / 60
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minutes % 60
+    }
+
+    pub fn is_day_over(&self) -> bool {
+        self.minutes >= DAY_END_MINUTES
+    }
+
+    pub fn is_shop_open(&self) -> bool {
+        (SHOP_OPEN_HOUR..SHOP_CLOSE_HOUR).contains(&self.hour())
+    }
+}
+
+impl std::fmt::Display for GameClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour() % 24, self.minute())
+    }
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    animal::animal_catalog, crop::crop_catalog, festival::festival_schedule,
+    machine::machine_products,
+};
+
+/// A way to turn items into an artisan good or a tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Recipe {
+    pub id: String,
+    pub name: String,
+    pub icon: String,
+    /// Item names and how many of each are consumed
+    pub ingredients: Vec<(String, u32)>,
+    /// Name of the item produced
+    pub output: String,
+    /// Game days until the item is ready
+    pub days: u32,
+    /// Coins the output sells for; tools can't be sold
+    pub sell_price: Option<u32>,
+    /// Farming level that unlocks the recipe
+    #[serde(default)]
+    pub level: u8,
+}
+
+impl Recipe {
+    fn new(
+        id: &str,
+        name: &str,
+        icon: &str,
+        ingredients: &[(&str, u32)],
+        days: u32,
+        sell_price: Option<u32>,
+        level: u8,
+    ) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            icon: icon.to_string(),
+            ingredients: ingredients
+                .iter()
+                .map(|(item, count)| (item.to_string(), *count))
+                .collect(),
+            output: name.to_string(),
+            days,
+            sell_price,
+            level,
+        }
+    }
+}
+
+/// An item being crafted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct CraftingJob {
+    pub recipe_id: String,
+    pub output: String,
+    pub ready_day: u32,
+}
+
+pub fn recipe_catalog() -> Vec<Recipe> {
+    vec![
+        Recipe::new("flour", "Flour", "🥣", &[("Wheat", 3)], 1, Some(380), 0),
+        Recipe::new(
+            "pickles",
+            "Pickles",
+            "🥒",
+            &[("Carrot", 3)],
+            2,
+            Some(200),
+            0,
+        ),
+        Recipe::new(
+            "melon_jam",
+            "Melon Jam",
+            "🍯",
+            &[("Melon", 2)],
+            3,
+            Some(650),
+            3,
+        ),
+        Recipe::new(
+            "strawberry_jam",
+            "Strawberry Jam",
+            "🍓",
+            &[("Strawberry", 3)],
+            2,
+            Some(480),
+            2,
+        ),
+        Recipe::new(
+            "basic_fertilizer",
+            "Basic Fertilizer",
+            "🟤",
+            &[("Potato", 2)],
+            1,
+            None,
+            0,
+        ),
+        Recipe::new(
+            "sprinkler",
+            "Sprinkler",
+            "💦",
+            &[("Corn", 2), ("Potato", 2)],
+            2,
+            None,
+            1,
+        ),
+        Recipe::new(
+            "quality_sprinkler",
+            "Quality Sprinkler",
+            "⛲",
+            &[("Sprinkler", 1), ("Pumpkin", 2)],
+            3,
+            None,
+            6,
+        ),
+        Recipe::new("keg", "Keg", "🛢️", &[("Wheat", 3), ("Corn", 1)], 2, None, 4),
+        Recipe::new(
+            "preserves_jar",
+            "Preserves Jar",
+            "🫙",
+            &[("Carrot", 2), ("Potato", 2)],
+            1,
+            None,
+            2,
+        ),
+        Recipe::new("mill", "Mill", "🏭", &[("Wheat", 5)], 2, None, 5),
+    ]
+}
+
+pub fn find_recipe(id: &str) -> Option<Recipe> {
+    recipe_catalog().into_iter().find(|recipe| recipe.id == id)
+}
+
+/// Coins an item sells for, if it can be sold
+pub fn item_sell_price(name: &str) -> Option<u32> {
+    if let Some(recipe) = recipe_catalog()
+        .into_iter()
+        .find(|recipe| recipe.output == name)
+    {
+        return recipe.sell_price;
+    }
+
+    if let Some(product) = machine_products()
+        .into_iter()
+        .find(|product| product.output == name)
+    {
+        return Some(product.sell_price);
+    }
+
+    if let Some(kind) = animal_catalog()
+        .into_iter()
+        .find(|kind| kind.product() == name)
+    {
+        return Some(kind.product_price());
+    }
+
+    crop_catalog()
+        .into_iter()
+        .chain(
+            festival_schedule()
+                .into_iter()
+                .flat_map(|festival| festival.special_seeds),
+        )
+        .find(|crop| crop.name == name)
+        .map(|crop| crop.sell_price)
+}
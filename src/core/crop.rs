@@ -4,7 +4,9 @@ use uuid::Uuid;
 use super::season::Season;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub struct Crop {
+    #[cfg_attr(feature = "wasm", tsify(type = "string"))]
     pub id: Uuid,
     pub name: String,
     pub growth_days: u8,
@@ -15,6 +17,73 @@ pub struct Crop {
     #[serde(default = "default_seasons")]
     pub seasons: Vec<Season>,
     pub icon: String,
+    /// Days of watering needed to produce again after a harvest; `None` for single-harvest crops
+    #[serde(default)]
+    pub regrow_days: Option<u8>,
+    /// Chance (0.0-1.0) that a harvest yields an extra crop
+    #[serde(default)]
+    pub extra_yield_chance: f32,
+    #[serde(default)]
+    pub times_harvested: u32,
+    #[serde(default)]
+    pub last_harvest_day: Option<u32>,
+    /// Farm plot the crop is planted in
+    #[serde(default)]
+    pub plot: Option<usize>,
+    /// Giant crops cover a 2x2 square of plots
+    #[serde(default)]
+    pub giant: bool,
+    #[serde(default)]
+    pub quality: CropQuality,
+    /// Growth days added by the soil the crop was planted in (negative grows faster)
+    #[serde(default)]
+    pub soil_modifier: i8,
+    /// Planted after a different crop family, improving quality
+    #[serde(default)]
+    pub rotation_bonus: bool,
+}
+
+/// Quality of a harvested crop, raising its sell price
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum CropQuality {
+    #[default]
+    Normal,
+    Silver,
+    Gold,
+}
+
+impl CropQuality {
+    /// Sell price multiplier in percent
+    pub fn price_percent(&self) -> u32 {
+        match self {
+            CropQuality::Normal => 100,
+            CropQuality::Silver => 125,
+            CropQuality::Gold => 150,
+        }
+    }
+
+    pub fn icon(&self) -> &str {
+        match self {
+            CropQuality::Normal => "",
+            CropQuality::Silver => "🥈",
+            CropQuality::Gold => "🥇",
+        }
+    }
+}
+
+/// Crop families used for crop rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum CropFamily {
+    Root,
+    Nightshade,
+    Grain,
+    Gourd,
+    Legume,
+    Fruit,
+    Flower,
+    Other,
 }
 
 fn default_seasons() -> Vec<Season> {
@@ -39,7 +108,96 @@ impl Crop {
             energy_cost: 15,
             seasons,
             icon: icon.to_string(),
+            regrow_days: None,
+            extra_yield_chance: 0.0,
+            times_harvested: 0,
+            last_harvest_day: None,
+            plot: None,
+            giant: false,
+            quality: CropQuality::Normal,
+            soil_modifier: 0,
+            rotation_bonus: false,
+        }
+    }
+
+    pub fn family(&self) -> CropFamily {
+        match self.name.as_str() {
+            "Carrot" | "Potato" | "Parsnip" | "Yam" => CropFamily::Root,
+            "Tomato" => CropFamily::Nightshade,
+            "Wheat" | "Corn" => CropFamily::Grain,
+            "Melon" | "Pumpkin" => CropFamily::Gourd,
+            "Snow Pea" => CropFamily::Legume,
+            "Strawberry" | "Starfruit" => CropFamily::Fruit,
+            "Sunflower" => CropFamily::Flower,
+            _ => CropFamily::Other,
+        }
+    }
+
+    /// Coins earned for harvesting this crop, including its quality bonus
+    pub fn harvest_value(&self) -> u32 {
+        self.sell_price * self.quality.price_percent() / 100
+    }
+
+    /// Coins the shop charges for a seed: half the crop's sell price
+    pub fn seed_price(&self) -> u32 {
+        (self.sell_price as f32 * 0.5) as u32
+    }
+
+    /// Make the crop produce again every `regrow_days` after its first harvest
+    pub fn with_regrowth(mut self, regrow_days: u8, extra_yield_chance: f32) -> Self {
+        self.regrow_days = Some(regrow_days);
+        self.extra_yield_chance = extra_yield_chance;
+        self
+    }
+
+    /// Whether the crop still needs water today
+    pub fn needs_water(&self, current_day: u32) -> bool {
+        !self.ready_harvest
+            && !self.watered_days.contains(&current_day)
+            && self.last_harvest_day != Some(current_day)
+    }
+
+    /// Days of watering needed before the next harvest
+    pub fn days_to_mature(&self) -> u8 {
+        let base = match self.regrow_days {
+            Some(regrow_days) if self.times_harvested > 0 => regrow_days,
+            _ => self.growth_days,
+        };
+
+        base.saturating_add_signed(self.soil_modifier).max(1)
+    }
+
+    /// Reset growth after a harvest. Returns false when the crop should be removed.
+    pub fn regrow(&mut self, current_day: u32) -> bool {
+        if self.regrow_days.is_none() || self.giant {
+            return false;
         }
+
+        self.times_harvested += 1;
+        self.last_harvest_day = Some(current_day);
+        self.watered_days.clear();
+        self.ready_harvest = false;
+        self.quality = CropQuality::Normal;
+
+        true
+    }
+
+    /// New variant seed produced by cross-pollinating two crops
+    pub fn hybrid(&self, other: &Crop) -> Crop {
+        let mut seasons = self.seasons.clone();
+        for season in &other.seasons {
+            if !seasons.contains(season) {
+                seasons.push(*season);
+            }
+        }
+
+        Crop::new(
+            &format!("{}-{} Hybrid", self.name, other.name),
+            self.growth_days.max(other.growth_days),
+            (self.sell_price + other.sell_price) * 3 / 4,
+            seasons,
+            &self.icon,
+        )
     }
 
     pub fn can_grow_in_season(&self, season: Season) -> bool {
@@ -60,7 +218,7 @@ pub fn initiate_starter_crops() -> Vec<Crop> {
             vec![Season::Spring, Season::Summer, Season::Autumn],
             "🥕",
         ),
-        Crop::new("Tomato", 5, 80, vec![Season::Summer], "🍅"),
+        Crop::new("Tomato", 5, 80, vec![Season::Summer], "🍅").with_regrowth(3, 0.2),
         Crop::new("Potato", 4, 60, vec![Season::Spring, Season::Autumn], "🥔"),
         Crop::new(
             "Wheat",
@@ -91,13 +249,15 @@ pub fn get_seasonal_crops(season: Season) -> Vec<Crop> {
             Crop::new("Parsnip", 4, 35, vec![Season::Spring], "🥔"),
         ],
         Season::Summer => vec![
-            Crop::new("Tomato", 5, 80, vec![Season::Summer], "🍅"),
-            Crop::new("Corn", 14, 150, vec![Season::Summer, Season::Autumn], "🌽"),
+            Crop::new("Tomato", 5, 80, vec![Season::Summer], "🍅").with_regrowth(3, 0.2),
+            Crop::new("Corn", 14, 150, vec![Season::Summer, Season::Autumn], "🌽")
+                .with_regrowth(4, 0.1),
             Crop::new("Melon", 12, 250, vec![Season::Summer], "🍈"),
         ],
         Season::Autumn => vec![
             Crop::new("Pumpkin", 13, 320, vec![Season::Autumn], "🎃"),
-            Crop::new("Corn", 14, 150, vec![Season::Summer, Season::Autumn], "🌽"),
+            Crop::new("Corn", 14, 150, vec![Season::Summer, Season::Autumn], "🌽")
+                .with_regrowth(4, 0.1),
             Crop::new("Yam", 10, 160, vec![Season::Autumn], "🍠"),
         ],
         Season::Winter => vec![
@@ -117,3 +277,23 @@ pub fn get_seasonal_crops(season: Season) -> Vec<Crop> {
         ],
     }
 }
+
+/// Every crop sold in the shop or given to new players
+pub fn crop_catalog() -> Vec<Crop> {
+    let mut crops = initiate_starter_crops();
+
+    for season in [
+        Season::Spring,
+        Season::Summer,
+        Season::Autumn,
+        Season::Winter,
+    ] {
+        for crop in get_seasonal_crops(season) {
+            if !crops.iter().any(|known| known.name == crop.name) {
+                crops.push(crop);
+            }
+        }
+    }
+
+    crops
+}
@@ -0,0 +1,113 @@
+use super::crop::Crop;
+
+/// Number of plot columns on the farm
+pub const FARM_WIDTH: usize = 6;
+/// Number of plot rows on the farm
+pub const FARM_HEIGHT: usize = 4;
+/// Total number of plots on the farm
+pub const FARM_PLOTS: usize = FARM_WIDTH * FARM_HEIGHT;
+
+/// Yield multiplier of a giant crop compared to a single crop
+pub const GIANT_CROP_YIELD: u32 = 8;
+/// Nightly chance that a 2x2 square of fully grown crops merges into a giant crop
+pub const GIANT_CROP_CHANCE: f32 = 0.25;
+/// Nightly chance that two adjacent fully grown crops cross-pollinate
+pub const MUTATION_CHANCE: f32 = 0.05;
+
+/// Column and row of a plot
+pub fn plot_coords(plot: usize) -> (usize, usize) {
+    (plot % FARM_WIDTH, plot / FARM_WIDTH)
+}
+
+/// Plot at the given column and row, if it is on the farm
+pub fn plot_at(x: usize, y: usize) -> Option<usize> {
+    (x < FARM_WIDTH && y < FARM_HEIGHT).then_some(y * FARM_WIDTH + x)
+}
+
+/// Plots sharing an edge with the given plot
+pub fn neighbors(plot: usize) -> Vec<usize> {
+    let (x, y) = plot_coords(plot);
+    let mut result = Vec::new();
+
+    if x > 0 {
+        result.extend(plot_at(x - 1, y));
+    }
+    if y > 0 {
+        result.extend(plot_at(x, y - 1));
+    }
+    result.extend(plot_at(x + 1, y));
+    result.extend(plot_at(x, y + 1));
+
+    result
+}
+
+/// Plots covered by a crop. Giant crops cover a 2x2 square from their plot.
+///
+/// Plots off the farm, e.g. from a damaged save, are left out.
+pub fn footprint(crop: &Crop) -> Vec<usize> {
+    let Some(plot) = crop.plot.filter(|&plot| plot < FARM_PLOTS) else {
+        return Vec::new();
+    };
+
+    if !crop.giant {
+        return vec![plot];
+    }
+
+    let (x, y) = plot_coords(plot);
+    [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]
+        .into_iter()
+        .filter_map(|(x, y)| plot_at(x, y))
+        .collect()
+}
+
+/// Index into `fields` of the crop covering each plot
+pub fn plot_map(fields: &[Crop]) -> Vec<Option<usize>> {
+    let mut map = vec![None; FARM_PLOTS];
+
+    for (index, crop) in fields.iter().enumerate() {
+        for plot in footprint(crop) {
+            map[plot] = Some(index);
+        }
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::season::Season;
+
+    fn giant_at(plot: usize) -> Crop {
+        let mut crop = Crop::new("Pumpkin", 4, 50, vec![Season::Autumn], "🎃");
+        crop.plot = Some(plot);
+        crop.giant = true;
+        crop
+    }
+
+    #[test]
+    fn giant_footprint_covers_a_two_by_two_square() {
+        assert_eq!(
+            footprint(&giant_at(0)),
+            vec![0, 1, FARM_WIDTH, FARM_WIDTH + 1]
+        );
+    }
+
+    #[test]
+    fn giant_footprint_stops_at_the_farm_edge() {
+        let right_edge = plot_at(FARM_WIDTH - 1, 0).unwrap();
+        assert_eq!(
+            footprint(&giant_at(right_edge)),
+            vec![right_edge, right_edge + FARM_WIDTH]
+        );
+
+        let bottom_edge = plot_at(0, FARM_HEIGHT - 1).unwrap();
+        assert_eq!(
+            footprint(&giant_at(bottom_edge)),
+            vec![bottom_edge, bottom_edge + 1]
+        );
+
+        assert_eq!(footprint(&giant_at(FARM_PLOTS - 1)), vec![FARM_PLOTS - 1]);
+        assert!(footprint(&giant_at(FARM_PLOTS)).is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    crop::{Crop, CropQuality},
+    season::{Calendar, Season},
+};
+
+/// A calendar event held on a fixed day of a season
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Festival {
+    pub name: String,
+    pub icon: String,
+    pub description: String,
+    pub season: Season,
+    pub day_in_season: u32,
+    /// Seeds sold by the shop only during the festival
+    pub special_seeds: Vec<Crop>,
+    /// Whether a crop judging contest is held
+    pub crop_contest: bool,
+}
+
+impl Festival {
+    fn new(
+        name: &str,
+        icon: &str,
+        description: &str,
+        season: Season,
+        day_in_season: u32,
+        special_seeds: Vec<Crop>,
+        crop_contest: bool,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            icon: icon.to_string(),
+            description: description.to_string(),
+            season,
+            day_in_season,
+            special_seeds,
+            crop_contest,
+        }
+    }
+}
+
+/// Outcome of entering a crop in a judging contest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct ContestResult {
+    pub score: u32,
+    pub placement: u8,
+    pub prize: u32,
+}
+
+/// Festivals of a year. Festival seeds are sold early enough in the season to
+/// mature before it ends in the default calendar.
+pub fn festival_schedule() -> Vec<Festival> {
+    vec![
+        Festival::new(
+            "Egg Festival",
+            "🥚",
+            "The town gathers for an egg hunt. Strawberry seeds are on sale!",
+            Season::Spring,
+            3,
+            vec![Crop::new("Strawberry", 6, 120, vec![Season::Spring], "🍓")],
+            false,
+        ),
+        Festival::new(
+            "Summer Luau",
+            "🌺",
+            "A beach feast with rare tropical seeds and a crop judging contest.",
+            Season::Summer,
+            3,
+            vec![Crop::new("Starfruit", 6, 400, vec![Season::Summer], "⭐")],
+            true,
+        ),
+        Festival::new(
+            "Harvest Fair",
+            "🎪",
+            "Show off your best crop to the judges at the autumn fair!",
+            Season::Autumn,
+            5,
+            vec![Crop::new(
+                "Sunflower",
+                5,
+                140,
+                vec![Season::Summer, Season::Autumn],
+                "🌻",
+            )],
+            true,
+        ),
+        Festival::new(
+            "Festival of Ice",
+            "⛄",
+            "Ice fishing and snow sculptures. Snow pea seeds are on sale!",
+            Season::Winter,
+            4,
+            vec![Crop::new("Snow Pea", 5, 90, vec![Season::Winter], "🫛")],
+            false,
+        ),
+    ]
+}
+
+/// Festival held on the given day, if any.
+///
+/// Festivals scheduled past the end of a short season are held on its last day.
+pub fn festival_on_day(calendar: &Calendar, day: u32) -> Option<Festival> {
+    let season = calendar.season_for_day(day);
+    let day_in_season = calendar.day_in_season(day);
+
+    festival_schedule().into_iter().find(|festival| {
+        festival.season == season
+            && festival.day_in_season.min(calendar.days_per_season) == day_in_season
+    })
+}
+
+/// Judge a fully grown crop on its quality, whatever kind of crop it is
+pub fn judge_crop(crop: &Crop) -> ContestResult {
+    let score = crop.quality.price_percent();
+
+    let (placement, prize) = match crop.quality {
+        CropQuality::Gold => (1, 1000),
+        CropQuality::Silver => (2, 500),
+        CropQuality::Normal => (3, 200),
+    };
+
+    ContestResult {
+        score,
+        placement,
+        prize,
+    }
+}
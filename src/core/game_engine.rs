@@ -1,13 +1,46 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    crop::get_seasonal_crops,
+    animal::{Animal, AnimalKind, BuildingKind, HAY_PRICE},
+    clock::{
+        BUILD_MINUTES, BUY_ITEM_MINUTES, BUY_SEED_MINUTES, CRAFT_MINUTES, DAY_END_MINUTES,
+        FEED_MINUTES_PER_ANIMAL, FERTILIZE_MINUTES, GameClock, HARVEST_MINUTES, MACHINE_MINUTES,
+        PLANT_MINUTES, SELL_ITEM_MINUTES, SHOP_CLOSE_HOUR, SHOP_OPEN_HOUR, SPRINKLER_MINUTES,
+        WATER_MINUTES_PER_CROP,
+    },
+    crafting::find_recipe,
+    crop::{Crop, get_seasonal_crops},
+    farm::{
+        FARM_HEIGHT, FARM_WIDTH, GIANT_CROP_CHANCE, MUTATION_CHANCE, plot_at, plot_coords, plot_map,
+    },
+    festival::{Festival, festival_on_day, judge_crop},
+    machine::machine_catalog,
     player::Player,
-    season::Season,
-    types::{GameCommand, GameEvent, GameInfo, GameResult, SeasonChangeEvent, TimeConfig},
+    season::{Calendar, Season},
+    skill::level_perks,
+    soil::fertilizer_catalog,
+    sprinkler::sprinkler_catalog,
+    types::{
+        GameCommand, GameEvent, GameInfo, GameResult, SeasonChangeEvent, SeasonWarning, TimeConfig,
+        TimeMode,
+    },
 };
 
 const STARTING_DAY: u32 = 1;
+/// Maximum number of days simulated when catching up on an old save
+const MAX_CATCH_UP_DAYS: u32 = 30;
+/// Days before a season change when crops at risk are reported
+const SEASON_WARNING_DAYS: u32 = 3;
+/// Maximum coins lost when passing out at 2am
+const PASS_OUT_MAX_PENALTY: u32 = 1000;
+
+/// What happened when the game moved to a new day
+struct DayChange {
+    season_change: Option<SeasonChangeEvent>,
+    /// Events that happened on the farm overnight
+    overnight: Vec<GameEvent>,
+}
 
 /// Core game engine - contains only pure game logic, no I/O operations
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -15,14 +48,31 @@ pub struct GameEngine {
     player: Player,
     day: u32,
     #[serde(default)]
+    calendar: Calendar,
+    #[serde(default)]
+    clock: GameClock,
+    #[serde(default)]
+    contest_entered_day: Option<u32>,
+    /// State of the seeded random number generator, saved so games replay deterministically
+    #[serde(default = "rand::random")]
+    rng_state: u64,
+    #[serde(default)]
     time_config: TimeConfig,
 }
 
 impl GameEngine {
     pub fn new(player: Player) -> Self {
+        Self::with_calendar(player, Calendar::default())
+    }
+
+    pub fn with_calendar(player: Player, calendar: Calendar) -> Self {
         Self {
             player,
             day: STARTING_DAY,
+            calendar,
+            clock: GameClock::default(),
+            contest_entered_day: None,
+            rng_state: rand::random(),
             time_config: TimeConfig::default(),
         }
     }
@@ -31,36 +81,190 @@ impl GameEngine {
         Self::new(Player::new(player_name))
     }
 
+    pub fn new_game_with_calendar(player_name: &str, calendar: Calendar) -> Self {
+        Self::with_calendar(Player::new(player_name), calendar)
+    }
+
     /// Execute a game command and return the result
     pub fn execute(&mut self, command: GameCommand) -> GameResult {
-        match command {
+        self.sync_clock(chrono::Utc::now());
+        let level = self.player.skills.farming_level();
+
+        let result = match command {
             GameCommand::Sleep => self.handle_sleep(),
-            GameCommand::PlantCrop { crop_index } => self.handle_plant_crop(crop_index),
+            GameCommand::PlantCrop { crop_index, plot } => self.handle_plant_crop(crop_index, plot),
             GameCommand::WaterCrops => self.handle_water_crops(),
             GameCommand::HarvestCrops => self.handle_harvest_crops(),
+            GameCommand::GatherCrops => self.handle_gather_crops(),
             GameCommand::AdvanceDay => self.handle_advance_day(),
             GameCommand::BuySeed { seed_name } => self.handle_buy_seed(seed_name),
+            GameCommand::SetTimeMode {
+                mode,
+                minutes_per_day,
+            } => self.handle_set_time_mode(mode, minutes_per_day),
+            GameCommand::EnterCropContest { field_index } => {
+                self.handle_enter_crop_contest(field_index)
+            }
+            GameCommand::BuyItem { item_name } => self.handle_buy_item(item_name),
+            GameCommand::ApplyFertilizer { plot, item_name } => {
+                self.handle_apply_fertilizer(plot, item_name)
+            }
+            GameCommand::PlaceSprinkler { plot, item_name } => {
+                self.handle_place_sprinkler(plot, item_name)
+            }
+            GameCommand::RemoveSprinkler { plot } => self.handle_remove_sprinkler(plot),
+            GameCommand::SellItem {
+                item_name,
+                quantity,
+            } => self.handle_sell_item(item_name, quantity),
+            GameCommand::Craft { recipe_id } => self.handle_craft(recipe_id),
+            GameCommand::PlaceMachine { plot, item_name } => {
+                self.handle_place_machine(plot, item_name)
+            }
+            GameCommand::RemoveMachine { plot } => self.handle_remove_machine(plot),
+            GameCommand::LoadMachine { plot, item_name } => {
+                self.handle_load_machine(plot, item_name)
+            }
+            GameCommand::CollectMachineProducts => self.handle_collect_machine_products(),
+            GameCommand::Build { building } => self.handle_build(building),
+            GameCommand::BuyAnimal { kind, name } => self.handle_buy_animal(kind, name),
+            GameCommand::FeedAnimals => self.handle_feed_animals(),
+        };
+
+        let result = match result {
+            GameResult::Success(event) if self.player.skills.farming_level() > level => {
+                let level = self.player.skills.farming_level();
+
+                GameResult::Success(GameEvent::LeveledUp {
+                    after: Box::new(event),
+                    level,
+                    perks: level_perks(level),
+                })
+            }
+            result => result,
+        };
+
+        match result {
+            GameResult::Success(event) if self.clock.is_day_over() => self.pass_out(event),
+            result => result,
         }
     }
 
     /// Get read-only information about current game state
     pub fn get_info(&self) -> GameInfo {
-        let current_season = Season::from_day(self.day);
+        let current_season = self.get_current_season();
+        let clock = self.current_clock(chrono::Utc::now());
 
         GameInfo {
             day: self.day,
             player_name: self.player.name.clone(),
             player_money: self.player.money,
             player_energy: self.player.energy,
-            max_energy: Player::MAX_ENERGY,
+            max_energy: self.player.max_energy(),
             current_season,
-            year: Season::year(self.day),
-            day_in_season: Season::day_in_season(self.day),
+            year: self.calendar.year(self.day),
+            day_in_season: self.calendar.day_in_season(self.day),
+            days_until_season_end: self.calendar.days_until_season_end(self.day),
+            next_season: self.calendar.next_season(self.day),
+            crops_at_risk: self.crops_at_risk(),
+            calendar: self.calendar.clone(),
             inventory: self.player.inventory.clone(),
             fields: self.player.fields.clone(),
+            plots: self.player.plots.clone(),
+            items: self.player.items.clone(),
+            sprinklers: self.player.sprinklers.clone(),
+            crafting: self.player.crafting.clone(),
+            machines: self.player.machines.clone(),
+            buildings: self.player.buildings.clone(),
+            animals: self.player.animals.clone(),
+            farming_level: self.player.skills.farming_level(),
+            farming_xp: self.player.skills.farming_xp,
+            next_level_xp: self.player.skills.next_level_xp(),
+            energy_discount: self.player.skills.energy_discount(),
+            time_mode: self.time_config.mode,
+            minutes_per_day: self.time_config.auto_day_change_minutes,
+            hour: clock.hour(),
+            minute: clock.minute(),
+            shop_open: self.shop_open_at(&clock),
+            festival: self.get_festival(),
         }
     }
 
+    /// Simulate the days missed since the last day change.
+    ///
+    /// Returns `None` when no full day has passed. At most `MAX_CATCH_UP_DAYS`
+    /// days are simulated, the rest are skipped.
+    pub fn catch_up(&mut self, now: chrono::DateTime<chrono::Utc>) -> Option<GameEvent> {
+        let (days_passed, days) = self.pass_real_time(now)?;
+
+        let days_simulated = days.len() as u32;
+        let season_changes = days
+            .into_iter()
+            .filter_map(|event| match event {
+                GameEvent::DayAdvanced { season_change, .. } => season_change,
+                _ => None,
+            })
+            .collect();
+
+        let crops_ready = self
+            .player
+            .fields
+            .iter()
+            .filter(|crop| crop.ready_harvest)
+            .map(|crop| crop.name.clone())
+            .collect();
+
+        Some(GameEvent::OfflineCatchUp {
+            days_passed,
+            days_simulated,
+            season_changes,
+            crops_ready,
+        })
+    }
+
+    /// Let real time pass up to `now`, returning the events to show the player.
+    ///
+    /// Front ends call this on a timer. Every real-time day passed since the last
+    /// day change starts a new day, up to `MAX_CATCH_UP_DAYS`; nothing happens
+    /// unless the game runs in real time.
+    pub fn tick(&mut self, now: chrono::DateTime<chrono::Utc>) -> Vec<GameEvent> {
+        self.pass_real_time(now)
+            .map(|(_, days)| days)
+            .unwrap_or_default()
+    }
+
+    /// Start a new day for each real-time day passed since the last day change.
+    ///
+    /// Returns the number of days passed with a `DayAdvanced` event for each day
+    /// started, or `None` if no full day has passed.
+    fn pass_real_time(
+        &mut self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<(u32, Vec<GameEvent>)> {
+        if !self.time_config.is_real_time() {
+            return None;
+        }
+
+        let last_change = self.time_config.last_day_change?;
+        let minutes_per_day = self.time_config.auto_day_change_minutes;
+        let days_passed = now.signed_duration_since(last_change).num_minutes() / minutes_per_day;
+
+        // A clock set back since the last save doesn't skip ahead
+        if days_passed <= 0 {
+            return None;
+        }
+
+        let days = (0..days_passed.min(MAX_CATCH_UP_DAYS as i64))
+            .map(|_| self.start_next_day())
+            .collect();
+
+        // Keep the partial day that has already elapsed
+        self.time_config.last_day_change =
+            Some(last_change + chrono::Duration::minutes(days_passed * minutes_per_day));
+
+        Some((u32::try_from(days_passed).unwrap_or(u32::MAX), days))
+    }
+
     pub fn get_time_config(&self) -> &TimeConfig {
         &self.time_config
     }
@@ -82,69 +286,289 @@ impl GameEngine {
     }
 
     pub fn get_current_season(&self) -> Season {
-        Season::from_day(self.day)
+        self.calendar.season_for_day(self.day)
+    }
+
+    pub fn get_calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Growing crops that will die at the next season change before they mature
+    pub fn crops_at_risk(&self) -> Vec<String> {
+        let next_season = self.calendar.next_season(self.day);
+        let days_left = self.calendar.days_until_season_end(self.day);
+
+        self.player
+            .fields
+            .iter()
+            .filter(|crop| !crop.ready_harvest && crop.dies_in_season(next_season))
+            .filter(|crop| {
+                let waterings_left =
+                    (crop.days_to_mature() as u32).saturating_sub(crop.watered_days.len() as u32);
+                let watering_days = if crop.watered_days.contains(&self.day) {
+                    days_left - 1
+                } else {
+                    days_left
+                };
+
+                waterings_left > watering_days
+            })
+            .map(|crop| crop.name.clone())
+            .collect()
+    }
+
+    /// Warning shown in the last days of a season when crops are at risk
+    fn season_warning(&self) -> Option<SeasonWarning> {
+        let days_until_season_end = self.calendar.days_until_season_end(self.day);
+        if days_until_season_end > SEASON_WARNING_DAYS {
+            return None;
+        }
+
+        let crops_at_risk = self.crops_at_risk();
+        if crops_at_risk.is_empty() {
+            return None;
+        }
+
+        Some(SeasonWarning {
+            next_season: self.calendar.next_season(self.day),
+            days_until_season_end,
+            crops_at_risk,
+        })
+    }
+
+    /// Festival held today, if any
+    pub fn get_festival(&self) -> Option<Festival> {
+        festival_on_day(&self.calendar, self.day)
+    }
+
+    /// Seeds sold by the shop today, including festival stock
+    pub fn available_seeds(&self) -> Vec<Crop> {
+        let mut seeds = get_seasonal_crops(self.get_current_season());
+
+        if let Some(festival) = self.get_festival() {
+            seeds.extend(festival.special_seeds);
+        }
+
+        seeds
+    }
+
+    /// Non-seed items sold in the shop, with their prices
+    pub fn shop_items(&self) -> Vec<(String, u32)> {
+        let fertilizers = fertilizer_catalog()
+            .into_iter()
+            .map(|fertilizer| (fertilizer.name, fertilizer.price));
+        let sprinklers = sprinkler_catalog()
+            .into_iter()
+            .map(|kind| (kind.name().to_string(), kind.price()));
+
+        let machines = machine_catalog()
+            .into_iter()
+            .map(|kind| (kind.name().to_string(), kind.price()));
+
+        fertilizers
+            .chain(sprinklers)
+            .chain(machines)
+            .chain([("Hay".to_string(), HAY_PRICE)])
+            .collect()
+    }
+
+    pub fn get_clock(&self) -> GameClock {
+        self.current_clock(chrono::Utc::now())
+    }
+
+    /// Time of day, taking real time into account in real-time mode
+    fn current_clock(&self, now: chrono::DateTime<chrono::Utc>) -> GameClock {
+        if !self.time_config.is_real_time() {
+            return self.clock;
+        }
+
+        let Some(last_change) = self.time_config.last_day_change else {
+            return self.clock;
+        };
+
+        let elapsed_ms = now.signed_duration_since(last_change).num_milliseconds();
+        let day_ms = self.time_config.auto_day_change_minutes * 60 * 1000;
+        let real_clock = GameClock::from_day_progress(elapsed_ms as f64 / day_ms as f64);
+
+        // Reaching the end of the day in real time is handled by the day monitor
+        GameClock {
+            minutes: self
+                .clock
+                .minutes
+                .max(real_clock.minutes.min(DAY_END_MINUTES - 1)),
+        }
+    }
+
+    fn sync_clock(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        self.clock = self.current_clock(now);
+    }
+
+    /// Shops keep hours only in real time; otherwise the clock may never reach them,
+    /// since it only moves when actions succeed, or not at all while paused
+    fn shop_open_at(&self, clock: &GameClock) -> bool {
+        !self.time_config.is_real_time() || clock.is_shop_open()
+    }
+
+    /// Spend game time on an action. The clock is frozen while time is paused.
+    fn spend_time(&mut self, minutes: u32) {
+        if self.time_config.mode != TimeMode::Paused {
+            self.clock.advance(minutes);
+        }
+    }
+
+    /// Random number generator for the next random event.
+    ///
+    /// The generator is reseeded from the saved state each time, so a save always
+    /// produces the same sequence of outcomes.
+    fn rng(&mut self) -> StdRng {
+        let mut rng = StdRng::seed_from_u64(self.rng_state);
+        self.rng_state = rng.random();
+        rng
+    }
+
+    /// The player stayed up until 2am: lose some money and wake up tired
+    fn pass_out(&mut self, after: GameEvent) -> GameResult {
+        let money_lost = (self.player.money / 10).min(PASS_OUT_MAX_PENALTY);
+        self.player.money -= money_lost;
+
+        let day_change = self.advance_day();
+        self.player.energy = self.player.max_energy() / 2;
+
+        GameResult::Success(GameEvent::PassedOut {
+            after: Box::new(after),
+            money_lost,
+            new_day: self.day,
+            remaining_energy: self.player.energy,
+            season_change: day_change.season_change,
+            overnight: day_change.overnight,
+            season_warning: self.season_warning(),
+            festival: self.get_festival(),
+        })
     }
 
     // Private command handlers
 
     fn handle_sleep(&mut self) -> GameResult {
+        if self.time_config.mode == TimeMode::Paused {
+            return GameResult::Error("Time is paused".to_string());
+        }
+
         let old_day = self.day;
-        let season_change = self.advance_day();
+        let day_change = self.advance_day();
 
         GameResult::Success(GameEvent::Slept {
             old_day,
             new_day: self.day,
-            season_change,
+            season_change: day_change.season_change,
+            overnight: day_change.overnight,
+            season_warning: self.season_warning(),
+            festival: self.get_festival(),
         })
     }
 
     fn handle_advance_day(&mut self) -> GameResult {
-        let season_change = self.advance_day();
+        if self.time_config.mode == TimeMode::Paused {
+            return GameResult::Error("Time is paused".to_string());
+        }
+
+        GameResult::Success(self.start_next_day())
+    }
 
-        GameResult::Success(GameEvent::DayAdvanced {
+    /// Advance to the next day, describing it as a `DayAdvanced` event
+    fn start_next_day(&mut self) -> GameEvent {
+        let day_change = self.advance_day();
+
+        GameEvent::DayAdvanced {
             new_day: self.day,
-            season_change,
-        })
+            season_change: day_change.season_change,
+            overnight: day_change.overnight,
+            season_warning: self.season_warning(),
+            festival: self.get_festival(),
+        }
     }
 
-    fn handle_plant_crop(&mut self, crop_index: usize) -> GameResult {
+    fn handle_plant_crop(&mut self, crop_index: usize, plot: Option<usize>) -> GameResult {
         if crop_index >= self.player.inventory.len() {
             return GameResult::Error("Invalid crop selection".to_string());
         }
 
         let crop = self.player.inventory[crop_index].clone();
 
-        match self.player.plant_crop(crop.clone()) {
-            Ok(_) => GameResult::Success(GameEvent::CropPlanted {
-                crop_name: crop.name,
-                remaining_energy: self.player.energy,
-            }),
+        self.player.assign_missing_plots();
+
+        match self.player.plant_crop(crop.clone(), plot) {
+            Ok(plot) => {
+                self.spend_time(PLANT_MINUTES);
+
+                GameResult::Success(GameEvent::CropPlanted {
+                    crop_name: crop.name,
+                    plot,
+                    remaining_energy: self.player.energy,
+                })
+            }
             Err(e) => GameResult::Error(e.to_string()),
         }
     }
 
     fn handle_water_crops(&mut self) -> GameResult {
         match self.player.water_crops(self.day) {
-            Ok(_) => GameResult::Success(GameEvent::CropsWatered {
-                remaining_energy: self.player.energy,
-            }),
+            Ok(watered) => {
+                self.spend_time(watered as u32 * WATER_MINUTES_PER_CROP);
+
+                GameResult::Success(GameEvent::CropsWatered {
+                    remaining_energy: self.player.energy,
+                })
+            }
             Err(e) => GameResult::Error(e.to_string()),
         }
     }
 
     fn handle_harvest_crops(&mut self) -> GameResult {
-        match self.player.harvest_crops() {
-            Ok(earnings) => GameResult::Success(GameEvent::CropsHarvested {
-                earnings,
-                total_money: self.player.money,
-            }),
+        let mut rng = self.rng();
+
+        match self.player.harvest_crops(self.day, false, &mut rng) {
+            Ok(harvest) => {
+                self.spend_time(HARVEST_MINUTES);
+
+                GameResult::Success(GameEvent::CropsHarvested {
+                    earnings: harvest.earnings,
+                    total_money: self.player.money,
+                    extra_yields: harvest.extra_yields,
+                    regrowing: harvest.regrowing,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_gather_crops(&mut self) -> GameResult {
+        let mut rng = self.rng();
+
+        match self.player.harvest_crops(self.day, true, &mut rng) {
+            Ok(harvest) => {
+                self.spend_time(HARVEST_MINUTES);
+
+                GameResult::Success(GameEvent::CropsGathered {
+                    produce: harvest.produce,
+                    earnings: harvest.earnings,
+                    quality_bonus: harvest.quality_bonus,
+                    total_money: self.player.money,
+                    regrowing: harvest.regrowing,
+                })
+            }
             Err(e) => GameResult::Error(e.to_string()),
         }
     }
 
     fn handle_buy_seed(&mut self, seed_name: String) -> GameResult {
-        let current_season = self.get_current_season();
-        let available_crops = get_seasonal_crops(current_season);
+        if !self.shop_open_at(&self.clock) {
+            return GameResult::Error(format!(
+                "The seed shop is closed (open {}:00-{}:00)",
+                SHOP_OPEN_HOUR, SHOP_CLOSE_HOUR
+            ));
+        }
+
+        let available_crops = self.available_seeds();
 
         // Find the crop by name
         let crop = match available_crops.iter().find(|c| c.name == seed_name) {
@@ -152,8 +576,7 @@ impl GameEngine {
             None => return GameResult::Error(format!("Seed '{}' not available", seed_name)),
         };
 
-        // Calculate seed cost (50% of sell price)
-        let seed_cost = (crop.sell_price as f32 * 0.5) as u32;
+        let seed_cost = crop.seed_price();
 
         // Check if player has enough money
         if self.player.money < seed_cost {
@@ -166,6 +589,7 @@ impl GameEngine {
         // Purchase the seed
         self.player.money -= seed_cost;
         self.player.inventory.push(crop.clone());
+        self.spend_time(BUY_SEED_MINUTES);
 
         GameResult::Success(GameEvent::SeedPurchased {
             seed_name: crop.name,
@@ -174,17 +598,323 @@ impl GameEngine {
         })
     }
 
+    fn handle_buy_item(&mut self, item_name: String) -> GameResult {
+        if !self.shop_open_at(&self.clock) {
+            return GameResult::Error(format!(
+                "The shop is closed (open {}:00-{}:00)",
+                SHOP_OPEN_HOUR, SHOP_CLOSE_HOUR
+            ));
+        }
+
+        let Some((item_name, cost)) = self
+            .shop_items()
+            .into_iter()
+            .find(|(name, _)| *name == item_name)
+        else {
+            return GameResult::Error(format!("Item '{}' not available", item_name));
+        };
+
+        if self.player.money < cost {
+            return GameResult::Error(format!(
+                "Not enough money! Need {} coins, have {}",
+                cost, self.player.money
+            ));
+        }
+
+        self.player.money -= cost;
+        self.player.add_item(&item_name, 1);
+        self.spend_time(BUY_ITEM_MINUTES);
+
+        GameResult::Success(GameEvent::ItemPurchased {
+            item_name,
+            cost,
+            remaining_money: self.player.money,
+        })
+    }
+
+    fn handle_apply_fertilizer(&mut self, plot: usize, item_name: String) -> GameResult {
+        match self.player.fertilize(plot, &item_name) {
+            Ok(fertility) => {
+                self.spend_time(FERTILIZE_MINUTES);
+
+                GameResult::Success(GameEvent::FertilizerApplied {
+                    item_name,
+                    plot,
+                    fertility,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_place_sprinkler(&mut self, plot: usize, item_name: String) -> GameResult {
+        match self.player.place_sprinkler(plot, &item_name) {
+            Ok(()) => {
+                self.spend_time(SPRINKLER_MINUTES);
+
+                GameResult::Success(GameEvent::SprinklerPlaced { item_name, plot })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_remove_sprinkler(&mut self, plot: usize) -> GameResult {
+        match self.player.remove_sprinkler(plot) {
+            Ok(sprinkler) => {
+                self.spend_time(SPRINKLER_MINUTES);
+
+                GameResult::Success(GameEvent::SprinklerRemoved {
+                    item_name: sprinkler.kind.name().to_string(),
+                    plot,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_sell_item(&mut self, item_name: String, quantity: u32) -> GameResult {
+        match self.player.sell_item(&item_name, quantity) {
+            Ok(earnings) => {
+                self.spend_time(SELL_ITEM_MINUTES);
+
+                GameResult::Success(GameEvent::ItemSold {
+                    item_name,
+                    quantity,
+                    earnings,
+                    total_money: self.player.money,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_craft(&mut self, recipe_id: String) -> GameResult {
+        let Some(recipe) = find_recipe(&recipe_id) else {
+            return GameResult::Error(format!("Unknown recipe '{}'", recipe_id));
+        };
+
+        match self.player.craft(&recipe, self.day) {
+            Ok(job) => {
+                self.spend_time(CRAFT_MINUTES);
+
+                GameResult::Success(GameEvent::CraftingStarted {
+                    recipe_name: recipe.name,
+                    ready_day: job.ready_day,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_place_machine(&mut self, plot: usize, item_name: String) -> GameResult {
+        match self.player.place_machine(plot, &item_name) {
+            Ok(()) => {
+                self.spend_time(MACHINE_MINUTES);
+
+                GameResult::Success(GameEvent::MachinePlaced { item_name, plot })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_remove_machine(&mut self, plot: usize) -> GameResult {
+        match self.player.remove_machine(plot) {
+            Ok(machine) => {
+                self.spend_time(MACHINE_MINUTES);
+
+                GameResult::Success(GameEvent::MachineRemoved {
+                    item_name: machine.kind.name().to_string(),
+                    plot,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_load_machine(&mut self, plot: usize, item_name: String) -> GameResult {
+        match self.player.load_machine(plot, &item_name, self.day) {
+            Ok(processing) => {
+                self.spend_time(MACHINE_MINUTES);
+
+                let machine_name = self
+                    .player
+                    .machine_at(plot)
+                    .map(|machine| machine.kind.name().to_string())
+                    .unwrap_or_default();
+
+                GameResult::Success(GameEvent::MachineLoaded {
+                    machine_name,
+                    input: processing.input,
+                    output: processing.output,
+                    ready_day: processing.ready_day,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_collect_machine_products(&mut self) -> GameResult {
+        match self.player.collect_machine_products() {
+            Ok(products) => {
+                self.spend_time(MACHINE_MINUTES);
+
+                GameResult::Success(GameEvent::MachineProductsCollected { products })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_build(&mut self, building: BuildingKind) -> GameResult {
+        if !self.shop_open_at(&self.clock) {
+            return GameResult::Error(format!(
+                "The carpenter is closed (open {}:00-{}:00)",
+                SHOP_OPEN_HOUR, SHOP_CLOSE_HOUR
+            ));
+        }
+
+        let cost = building.price();
+        if self.player.money < cost {
+            return GameResult::Error(format!(
+                "Not enough money! Need {} coins, have {}",
+                cost, self.player.money
+            ));
+        }
+
+        self.player.money -= cost;
+        self.player.buildings.push(building);
+        self.spend_time(BUILD_MINUTES);
+
+        GameResult::Success(GameEvent::BuildingBuilt {
+            building,
+            cost,
+            remaining_money: self.player.money,
+        })
+    }
+
+    fn handle_buy_animal(&mut self, kind: AnimalKind, name: String) -> GameResult {
+        if !self.shop_open_at(&self.clock) {
+            return GameResult::Error(format!(
+                "The ranch is closed (open {}:00-{}:00)",
+                SHOP_OPEN_HOUR, SHOP_CLOSE_HOUR
+            ));
+        }
+
+        let name = match name.trim() {
+            "" => kind.name().to_string(),
+            name => name.to_string(),
+        };
+
+        let cost = kind.price();
+        if self.player.money < cost {
+            return GameResult::Error(format!(
+                "Not enough money! Need {} coins, have {}",
+                cost, self.player.money
+            ));
+        }
+
+        if let Err(e) = self.player.add_animal(Animal::new(&name, kind)) {
+            return GameResult::Error(e.to_string());
+        }
+
+        self.player.money -= cost;
+        self.spend_time(BUY_ITEM_MINUTES);
+
+        GameResult::Success(GameEvent::AnimalPurchased {
+            name,
+            kind,
+            cost,
+            remaining_money: self.player.money,
+        })
+    }
+
+    fn handle_feed_animals(&mut self) -> GameResult {
+        match self.player.feed_animals() {
+            Ok(feeding) => {
+                self.spend_time(feeding.fed * FEED_MINUTES_PER_ANIMAL);
+
+                GameResult::Success(GameEvent::AnimalsFed {
+                    fed: feeding.fed,
+                    feed_used: feeding.feed_used,
+                    hungry: feeding.hungry,
+                })
+            }
+            Err(e) => GameResult::Error(e.to_string()),
+        }
+    }
+
+    fn handle_set_time_mode(&mut self, mode: TimeMode, minutes_per_day: Option<i64>) -> GameResult {
+        if let Some(minutes) = minutes_per_day {
+            if minutes < 1 {
+                return GameResult::Error("Minutes per day must be at least 1".to_string());
+            }
+
+            self.time_config.auto_day_change_minutes = minutes;
+        }
+
+        // Restart the real-time clock so time spent in other modes is not counted
+        if mode == TimeMode::RealTime && self.time_config.mode != TimeMode::RealTime {
+            self.time_config.last_day_change = Some(chrono::Utc::now());
+        }
+
+        self.time_config.mode = mode;
+
+        GameResult::Success(GameEvent::TimeModeChanged {
+            mode,
+            minutes_per_day: self.time_config.auto_day_change_minutes,
+        })
+    }
+
+    fn handle_enter_crop_contest(&mut self, field_index: usize) -> GameResult {
+        let festival = match self.get_festival() {
+            Some(festival) if festival.crop_contest => festival,
+            _ => return GameResult::Error("There is no crop contest today".to_string()),
+        };
+
+        if self.contest_entered_day == Some(self.day) {
+            return GameResult::Error("You already entered today's contest".to_string());
+        }
+
+        let Some(crop) = self.player.fields.get(field_index) else {
+            return GameResult::Error("Invalid crop selection".to_string());
+        };
+
+        if !crop.ready_harvest {
+            return GameResult::Error(format!("{} is not fully grown yet", crop.name));
+        }
+
+        // The judges keep the entry
+        let crop = self.player.fields.remove(field_index);
+        let result = judge_crop(&crop);
+
+        self.player.money += result.prize;
+        self.contest_entered_day = Some(self.day);
+
+        GameResult::Success(GameEvent::CropContestJudged {
+            festival_name: festival.name,
+            crop_name: crop.name,
+            score: result.score,
+            placement: result.placement,
+            prize: result.prize,
+            total_money: self.player.money,
+        })
+    }
+
     /// Advance to next day and handle season changes
-    fn advance_day(&mut self) -> Option<SeasonChangeEvent> {
+    fn advance_day(&mut self) -> DayChange {
         let old_season = self.get_current_season();
 
         self.player.sleep();
         self.day += 1;
+        self.clock = GameClock::default();
         self.time_config.last_day_change = Some(chrono::Utc::now());
 
-        let new_season = Season::from_day(self.day);
+        self.player.assign_missing_plots();
+        let mut overnight = self.form_giant_crops();
+        overnight.extend(self.cross_pollinate());
+
+        let new_season = self.get_current_season();
 
-        if old_season != new_season {
+        let season_change = if old_season != new_season {
             let crops_died = self.handle_season_change(new_season);
 
             Some(SeasonChangeEvent {
@@ -195,9 +925,133 @@ impl GameEngine {
             })
         } else {
             None
+        };
+
+        let watered = self.player.run_sprinklers(self.day);
+        if !watered.is_empty() {
+            overnight.push(GameEvent::SprinklersWatered { crops: watered });
+        }
+
+        let crafted = self.player.finish_crafting(self.day);
+        if !crafted.is_empty() {
+            overnight.push(GameEvent::CraftingFinished { items: crafted });
+        }
+
+        let products = self.player.run_machines(self.day);
+        if !products.is_empty() {
+            overnight.push(GameEvent::MachinesFinished { products });
+        }
+
+        if !self.player.animals.is_empty() {
+            let (products, hungry) = self.player.tend_animals();
+            overnight.push(GameEvent::AnimalsTended { products, hungry });
+        }
+
+        DayChange {
+            season_change,
+            overnight,
         }
     }
 
+    /// Merge 2x2 squares of the same fully grown crop into giant crops
+    fn form_giant_crops(&mut self) -> Vec<GameEvent> {
+        let mut events = Vec::new();
+        let mut rng = self.rng();
+
+        for y in 0..FARM_HEIGHT - 1 {
+            for x in 0..FARM_WIDTH - 1 {
+                let map = plot_map(&self.player.fields);
+                let square: Vec<Option<usize>> = [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]
+                    .into_iter()
+                    .map(|(x, y)| plot_at(x, y).and_then(|plot| map[plot]))
+                    .collect();
+
+                let Some(indices) = square.into_iter().collect::<Option<Vec<usize>>>() else {
+                    continue;
+                };
+
+                let first = &self.player.fields[indices[0]];
+                let eligible = indices.iter().all(|&index| {
+                    let crop = &self.player.fields[index];
+                    crop.ready_harvest && !crop.giant && crop.name == first.name
+                });
+
+                if !eligible || rng.random::<f32>() >= GIANT_CROP_CHANCE {
+                    continue;
+                }
+
+                let giant_index = indices[0];
+                let plot = plot_at(x, y);
+                let crop_name = self.player.fields[giant_index].name.clone();
+
+                let giant = &mut self.player.fields[giant_index];
+                giant.giant = true;
+                giant.plot = plot;
+
+                let merged: Vec<_> = indices[1..]
+                    .iter()
+                    .map(|&index| self.player.fields[index].id)
+                    .collect();
+                self.player.fields.retain(|crop| !merged.contains(&crop.id));
+
+                events.push(GameEvent::GiantCropFormed {
+                    crop_name,
+                    plot: plot.unwrap_or_default(),
+                });
+            }
+        }
+
+        events
+    }
+
+    /// Adjacent fully grown crops of different types may produce a hybrid seed
+    fn cross_pollinate(&mut self) -> Vec<GameEvent> {
+        let mut rng = self.rng();
+        let map = plot_map(&self.player.fields);
+
+        for (plot, crop_index) in map.iter().enumerate() {
+            let Some(crop_index) = *crop_index else {
+                continue;
+            };
+
+            // Only look right and down so each pair is checked once
+            let (x, y) = plot_coords(plot);
+            for neighbor in [plot_at(x + 1, y), plot_at(x, y + 1)].into_iter().flatten() {
+                let Some(neighbor_index) = map[neighbor] else {
+                    continue;
+                };
+
+                let crop = &self.player.fields[crop_index];
+                let other = &self.player.fields[neighbor_index];
+
+                if !crop.ready_harvest
+                    || !other.ready_harvest
+                    || crop.giant
+                    || other.giant
+                    || crop.name == other.name
+                {
+                    continue;
+                }
+
+                if rng.random::<f32>() < MUTATION_CHANCE {
+                    let seed = crop.hybrid(other);
+                    let event = GameEvent::CropMutated {
+                        parent_a: crop.name.clone(),
+                        parent_b: other.name.clone(),
+                        seed_name: seed.name.clone(),
+                    };
+
+                    self.player.inventory.push(seed);
+
+                    // At most one mutation per night
+                    return vec![event];
+                }
+            }
+        }
+
+        Vec::new()
+    }
+
     fn handle_season_change(&mut self, new_season: Season) -> Vec<String> {
         let mut died_crops = Vec::new();
 
@@ -213,3 +1067,279 @@ impl GameEngine {
         died_crops
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    use super::*;
+    use crate::core::farm::footprint;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap()
+    }
+
+    /// A real-time game whose days last an hour, last changed at `start()`
+    fn hourly_engine(calendar: Calendar) -> GameEngine {
+        let mut engine = GameEngine::new_game_with_calendar("Tester", calendar);
+        engine.set_time_config(TimeConfig {
+            mode: TimeMode::RealTime,
+            auto_day_change_minutes: 60,
+            last_day_change: Some(start()),
+        });
+        engine
+    }
+
+    #[test]
+    fn catch_up_ignores_a_clock_set_back() {
+        let mut engine = hourly_engine(Calendar::default());
+
+        assert!(engine.catch_up(start() - Duration::hours(5)).is_none());
+        assert_eq!(engine.day, STARTING_DAY);
+        assert_eq!(engine.time_config.last_day_change, Some(start()));
+    }
+
+    #[test]
+    fn catch_up_waits_for_a_full_day() {
+        let mut engine = hourly_engine(Calendar::default());
+
+        assert!(engine.catch_up(start() + Duration::minutes(59)).is_none());
+        assert_eq!(engine.day, STARTING_DAY);
+        assert_eq!(engine.time_config.last_day_change, Some(start()));
+    }
+
+    #[test]
+    fn catch_up_simulates_each_missed_day_and_keeps_the_partial_day() {
+        let mut engine = hourly_engine(Calendar::default());
+
+        let event = engine.catch_up(start() + Duration::minutes(3 * 60 + 25));
+
+        assert!(matches!(
+            event,
+            Some(GameEvent::OfflineCatchUp {
+                days_passed: 3,
+                days_simulated: 3,
+                ..
+            })
+        ));
+        assert_eq!(engine.day, STARTING_DAY + 3);
+        assert_eq!(
+            engine.time_config.last_day_change,
+            Some(start() + Duration::hours(3))
+        );
+    }
+
+    #[test]
+    fn catch_up_simulates_at_most_max_catch_up_days() {
+        let mut engine = hourly_engine(Calendar::default());
+
+        let event = engine.catch_up(start() + Duration::hours(45));
+
+        assert!(matches!(
+            event,
+            Some(GameEvent::OfflineCatchUp {
+                days_passed: 45,
+                days_simulated: MAX_CATCH_UP_DAYS,
+                ..
+            })
+        ));
+        assert_eq!(engine.day, STARTING_DAY + MAX_CATCH_UP_DAYS);
+        assert_eq!(
+            engine.time_config.last_day_change,
+            Some(start() + Duration::hours(45))
+        );
+    }
+
+    #[test]
+    fn catch_up_summarises_season_changes_and_ready_crops() {
+        let calendar =
+            Calendar::new(3, vec![Season::Spring, Season::Summer], Season::Spring).unwrap();
+        let mut engine = hourly_engine(calendar);
+
+        let mut crop = Crop::new("Corn", 4, 50, vec![Season::Spring, Season::Summer], "🌽");
+        crop.plot = Some(0);
+        crop.ready_harvest = true;
+        engine.player.fields = vec![crop];
+
+        let Some(GameEvent::OfflineCatchUp {
+            days_passed,
+            season_changes,
+            crops_ready,
+            ..
+        }) = engine.catch_up(start() + Duration::hours(5))
+        else {
+            panic!("expected an offline catch-up summary");
+        };
+
+        assert_eq!(days_passed, 5);
+        assert_eq!(season_changes.len(), 1);
+        assert_eq!(season_changes[0].old_season, Season::Spring);
+        assert_eq!(season_changes[0].new_season, Season::Summer);
+        assert_eq!(crops_ready, vec!["Corn".to_string()]);
+    }
+
+    /// Plant a fully grown crop of the given kind on each plot
+    fn grown_crops(name: &str, plots: &[usize]) -> Vec<Crop> {
+        plots
+            .iter()
+            .map(|&plot| {
+                let mut crop = Crop::new(name, 4, 50, vec![Season::Spring], "🥦");
+                crop.plot = Some(plot);
+                crop.ready_harvest = true;
+                crop
+            })
+            .collect()
+    }
+
+    /// First RNG seed whose next roll does or doesn't form a giant crop
+    fn giant_crop_seed(forms: bool) -> u64 {
+        let mut engine = GameEngine::new_game("Tester");
+        (0..)
+            .find(|&seed| {
+                engine.rng_state = seed;
+                (engine.rng().random::<f32>() < GIANT_CROP_CHANCE) == forms
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn four_adjacent_grown_crops_merge_into_a_giant_crop() {
+        let mut engine = GameEngine::new_game("Tester");
+        let corner = plot_at(FARM_WIDTH - 2, FARM_HEIGHT - 2).unwrap();
+        let square = [
+            corner,
+            corner + 1,
+            corner + FARM_WIDTH,
+            corner + FARM_WIDTH + 1,
+        ];
+        engine.player.fields = grown_crops("Cauliflower", &square);
+        engine.rng_state = giant_crop_seed(true);
+
+        let events = engine.form_giant_crops();
+
+        assert!(matches!(
+            events.as_slice(),
+            [GameEvent::GiantCropFormed { crop_name, plot }]
+                if crop_name == "Cauliflower" && *plot == corner
+        ));
+        assert_eq!(engine.player.fields.len(), 1);
+        let giant = &engine.player.fields[0];
+        assert!(giant.giant);
+        assert_eq!(footprint(giant), square);
+    }
+
+    #[test]
+    fn giant_crops_need_a_lucky_roll_and_matching_crops() {
+        let mut engine = GameEngine::new_game("Tester");
+        engine.player.fields = grown_crops("Cauliflower", &[0, 1, FARM_WIDTH, FARM_WIDTH + 1]);
+        engine.rng_state = giant_crop_seed(false);
+
+        assert!(engine.form_giant_crops().is_empty());
+        assert_eq!(engine.player.fields.len(), 4);
+
+        let mut fields = grown_crops("Cauliflower", &[0, 1, FARM_WIDTH]);
+        fields.extend(grown_crops("Pumpkin", &[FARM_WIDTH + 1]));
+        engine.player.fields = fields;
+        engine.rng_state = giant_crop_seed(true);
+
+        assert!(engine.form_giant_crops().is_empty());
+        assert!(engine.player.fields.iter().all(|crop| !crop.giant));
+    }
+
+    #[test]
+    fn festival_seeds_bought_on_the_day_mature_before_the_season_ends() {
+        let calendar = Calendar::default();
+
+        for day in 1..=calendar.days_per_year() {
+            let Some(festival) = festival_on_day(&calendar, day) else {
+                continue;
+            };
+            let season = calendar.season_for_day(day);
+
+            let mut engine = GameEngine::new_game_with_calendar("Tester", calendar.clone());
+            engine.time_config.mode = TimeMode::TurnBased;
+            engine.day = day;
+            engine.player.inventory.clear();
+            engine.player.fields.clear();
+            engine.player.money = 100_000;
+
+            for seed in festival.special_seeds {
+                assert!(matches!(
+                    engine.execute(GameCommand::BuySeed {
+                        seed_name: seed.name.clone()
+                    }),
+                    GameResult::Success(_)
+                ));
+                assert!(matches!(
+                    engine.execute(GameCommand::PlantCrop {
+                        crop_index: 0,
+                        plot: None
+                    }),
+                    GameResult::Success(_)
+                ));
+
+                loop {
+                    engine.execute(GameCommand::WaterCrops);
+                    if engine.player.fields[0].ready_harvest {
+                        break;
+                    }
+
+                    engine.execute(GameCommand::Sleep);
+                    assert_eq!(
+                        engine.get_current_season(),
+                        season,
+                        "{} bought at the {} did not mature in time",
+                        seed.name,
+                        festival.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tick_advances_one_day_per_elapsed_day() {
+        let mut engine = hourly_engine(Calendar::default());
+
+        assert!(engine.tick(start() + Duration::minutes(30)).is_empty());
+
+        let events = engine.tick(start() + Duration::minutes(2 * 60 + 10));
+        assert_eq!(events.len(), 2);
+        assert!(
+            events
+                .iter()
+                .all(|event| matches!(event, GameEvent::DayAdvanced { .. }))
+        );
+        assert_eq!(engine.day, STARTING_DAY + 2);
+
+        // The partial day carries over to the next tick
+        assert!(
+            engine
+                .tick(start() + Duration::minutes(2 * 60 + 50))
+                .is_empty()
+        );
+        assert_eq!(engine.tick(start() + Duration::hours(3)).len(), 1);
+        assert_eq!(engine.day, STARTING_DAY + 3);
+    }
+
+    #[test]
+    fn tick_does_nothing_when_turn_based_or_paused() {
+        for mode in [TimeMode::TurnBased, TimeMode::Paused] {
+            let mut engine = hourly_engine(Calendar::default());
+            engine.time_config.mode = mode;
+
+            assert!(engine.tick(start() + Duration::hours(5)).is_empty());
+            assert_eq!(engine.day, STARTING_DAY);
+            assert_eq!(engine.time_config.last_day_change, Some(start()));
+        }
+    }
+
+    #[test]
+    fn catch_up_does_nothing_unless_real_time() {
+        let mut engine = hourly_engine(Calendar::default());
+        engine.time_config.mode = TimeMode::TurnBased;
+
+        assert!(engine.catch_up(start() + Duration::hours(5)).is_none());
+        assert_eq!(engine.day, STARTING_DAY);
+    }
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+/// Machines that turn crops into artisan goods
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum MachineKind {
+    Keg,
+    PreservesJar,
+    Mill,
+}
+
+impl MachineKind {
+    pub fn name(&self) -> &str {
+        match self {
+            MachineKind::Keg => "Keg",
+            MachineKind::PreservesJar => "Preserves Jar",
+            MachineKind::Mill => "Mill",
+        }
+    }
+
+    pub fn icon(&self) -> &str {
+        match self {
+            MachineKind::Keg => "🛢️",
+            MachineKind::PreservesJar => "🫙",
+            MachineKind::Mill => "🏭",
+        }
+    }
+
+    pub fn price(&self) -> u32 {
+        match self {
+            MachineKind::Keg => 600,
+            MachineKind::PreservesJar => 400,
+            MachineKind::Mill => 500,
+        }
+    }
+}
+
+/// What a machine makes from one input item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct MachineProduct {
+    pub machine: MachineKind,
+    pub input: String,
+    pub output: String,
+    pub days: u32,
+    pub sell_price: u32,
+}
+
+impl MachineProduct {
+    fn new(machine: MachineKind, input: &str, output: &str, days: u32, sell_price: u32) -> Self {
+        Self {
+            machine,
+            input: input.to_string(),
+            output: output.to_string(),
+            days,
+            sell_price,
+        }
+    }
+}
+
+/// An item being processed by a machine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Processing {
+    pub input: String,
+    pub output: String,
+    pub ready_day: u32,
+    /// Set overnight once `ready_day` arrives
+    #[serde(default)]
+    pub ready: bool,
+}
+
+/// A machine placed on a farm plot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Machine {
+    pub kind: MachineKind,
+    pub plot: usize,
+    pub processing: Option<Processing>,
+}
+
+pub fn machine_catalog() -> Vec<MachineKind> {
+    vec![
+        MachineKind::Keg,
+        MachineKind::PreservesJar,
+        MachineKind::Mill,
+    ]
+}
+
+pub fn find_machine(name: &str) -> Option<MachineKind> {
+    machine_catalog()
+        .into_iter()
+        .find(|kind| kind.name() == name)
+}
+
+pub fn machine_products() -> Vec<MachineProduct> {
+    vec![
+        MachineProduct::new(MachineKind::Keg, "Tomato", "Tomato Juice", 3, 250),
+        MachineProduct::new(MachineKind::Keg, "Melon", "Melon Wine", 5, 750),
+        MachineProduct::new(MachineKind::Keg, "Strawberry", "Strawberry Wine", 5, 400),
+        MachineProduct::new(MachineKind::Keg, "Starfruit", "Starfruit Wine", 7, 1200),
+        MachineProduct::new(
+            MachineKind::PreservesJar,
+            "Carrot",
+            "Pickled Carrots",
+            2,
+            180,
+        ),
+        MachineProduct::new(
+            MachineKind::PreservesJar,
+            "Potato",
+            "Pickled Potatoes",
+            2,
+            210,
+        ),
+        MachineProduct::new(MachineKind::PreservesJar, "Yam", "Yam Preserves", 3, 500),
+        MachineProduct::new(
+            MachineKind::PreservesJar,
+            "Pumpkin",
+            "Pumpkin Preserves",
+            3,
+            900,
+        ),
+        MachineProduct::new(MachineKind::Mill, "Corn", "Cornmeal", 1, 300),
+        MachineProduct::new(MachineKind::Mill, "Sunflower", "Sunflower Oil", 1, 350),
+    ]
+}
+
+/// What the given machine makes from an input item, if it accepts it
+pub fn find_product(machine: MachineKind, input: &str) -> Option<MachineProduct> {
+    machine_products()
+        .into_iter()
+        .find(|product| product.machine == machine && product.input == input)
+}
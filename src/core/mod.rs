@@ -1,10 +1,23 @@
+pub mod animal;
+pub mod clock;
+pub mod crafting;
 pub mod crop;
+pub mod farm;
+pub mod festival;
 pub mod game_engine;
+pub mod machine;
+pub mod persistence;
 pub mod player;
+pub mod save_bundle;
 pub mod season;
+pub mod skill;
+pub mod soil;
+pub mod sprinkler;
+pub mod trade;
 pub mod types;
 
 // Re-export commonly used types
 pub use game_engine::GameEngine;
 pub use player::Player;
-pub use season::Season;
+pub use save_bundle::SaveBundle;
+pub use season::{Calendar, Season};
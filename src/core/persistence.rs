@@ -0,0 +1,351 @@
+use anyhow::{Context, Result, bail};
+
+use super::{game_engine::GameEngine, save_bundle::SaveBundle};
+
+/// Slot used when the player doesn't pick one
+pub const DEFAULT_SLOT: &str = "default";
+/// Older saves kept for each slot, newest first
+pub const BACKUP_COUNT: usize = 3;
+
+const SAVE_KEY: &str = "game-state";
+const BACKUP_MARKER: &str = ".backup.";
+const MAX_SLOT_LEN: usize = 32;
+
+/// Key-value store holding saved games, e.g. files or the browser's localStorage
+pub trait SaveStorage {
+    /// Read the value stored under a key, or `None` if there isn't one
+    fn read(&self, key: &str) -> Result<Option<String>>;
+    fn write(&self, key: &str, value: &str) -> Result<()>;
+    /// Remove a key, doing nothing if it isn't stored
+    fn remove(&self, key: &str) -> Result<()>;
+    /// Every key in the store, including ones that aren't saves
+    fn keys(&self) -> Result<Vec<String>>;
+}
+
+/// Named save slots with backups, on top of any `SaveStorage`.
+///
+/// Saves are written as versioned, checksummed `SaveBundle`s, while plain game
+/// state JSON from older versions still loads. Before a save overwrites one from
+/// an earlier day, the old save becomes the newest backup, so the backups hold
+/// the last few days played.
+pub struct SaveSlots<S> {
+    storage: S,
+}
+
+impl<S: SaveStorage> SaveSlots<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    /// Load the game saved in a slot, or `None` if the slot is empty
+    pub fn load(&self, slot: &str) -> Result<Option<GameEngine>> {
+        let Some(save) = self.storage.read(&save_key(slot)?)? else {
+            return Ok(None);
+        };
+
+        parse_save(&save)
+            .with_context(|| format!("Failed to load save slot '{}'", slot))
+            .map(Some)
+    }
+
+    /// Save a game to a slot, backing up the slot's save if it is from another day
+    pub fn save(&self, slot: &str, engine: &GameEngine) -> Result<()> {
+        let key = save_key(slot)?;
+        let bundle = SaveBundle::from_engine(engine)?;
+        let json = serde_json::to_string(&bundle).context("Failed to serialize save bundle")?;
+
+        if let Some(previous) = self.storage.read(&key)? {
+            let same_day =
+                parse_save(&previous).is_ok_and(|saved| saved.get_day() == engine.get_day());
+            if !same_day {
+                self.rotate_backups(&key, &previous)?;
+            }
+        }
+
+        self.storage.write(&key, &json)
+    }
+
+    /// Check if a slot holds a save
+    pub fn exists(&self, slot: &str) -> Result<bool> {
+        Ok(self.storage.read(&save_key(slot)?)?.is_some())
+    }
+
+    /// Delete the save in a slot, keeping its backups so it can be restored
+    pub fn delete(&self, slot: &str) -> Result<()> {
+        self.storage.remove(&save_key(slot)?)
+    }
+
+    /// Names of the slots holding a save, sorted
+    pub fn slots(&self) -> Result<Vec<String>> {
+        let mut slots: Vec<String> = self
+            .storage
+            .keys()?
+            .into_iter()
+            .filter(|key| !key.contains(BACKUP_MARKER))
+            .filter_map(|key| match key.strip_prefix(SAVE_KEY)? {
+                "" => Some(DEFAULT_SLOT.to_string()),
+                slot => slot.strip_prefix('.').map(str::to_string),
+            })
+            .collect();
+        slots.sort();
+
+        Ok(slots)
+    }
+
+    /// Day of each backup kept for a slot, newest first.
+    ///
+    /// A backup that can't be read is reported in its place, so the others are
+    /// still listed under the numbers they are restored by.
+    pub fn backups(&self, slot: &str) -> Result<Vec<Result<u32>>> {
+        let key = save_key(slot)?;
+        let mut days = Vec::new();
+        for backup in 1..=BACKUP_COUNT {
+            let Some(save) = self.storage.read(&backup_key(&key, backup))? else {
+                break;
+            };
+            days.push(
+                parse_save(&save)
+                    .map(|engine| engine.get_day())
+                    .with_context(|| {
+                        format!("Failed to read backup {} of slot '{}'", backup, slot)
+                    }),
+            );
+        }
+
+        Ok(days)
+    }
+
+    /// Replace a slot's save with one of its backups, numbered from 1 (newest)
+    pub fn restore_backup(&self, slot: &str, backup: usize) -> Result<GameEngine> {
+        let key = save_key(slot)?;
+        if backup == 0 || backup > BACKUP_COUNT {
+            bail!("Backups are numbered from 1 to {}", BACKUP_COUNT);
+        }
+
+        let save = self
+            .storage
+            .read(&backup_key(&key, backup))?
+            .with_context(|| format!("Slot '{}' has no backup {}", slot, backup))?;
+        let engine = parse_save(&save)
+            .with_context(|| format!("Failed to read backup {} of slot '{}'", backup, slot))?;
+        self.save(slot, &engine)?;
+
+        Ok(engine)
+    }
+
+    /// Shift the backups along one place, making `previous` the newest
+    fn rotate_backups(&self, key: &str, previous: &str) -> Result<()> {
+        for backup in (1..BACKUP_COUNT).rev() {
+            if let Some(older) = self.storage.read(&backup_key(key, backup))? {
+                self.storage.write(&backup_key(key, backup + 1), &older)?;
+            }
+        }
+
+        self.storage.write(&backup_key(key, 1), previous)
+    }
+}
+
+/// Check a slot name is safe to use as part of a file name or storage key
+pub fn validate_slot(slot: &str) -> Result<()> {
+    let valid_chars = slot
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if slot.is_empty() || slot.len() > MAX_SLOT_LEN || !valid_chars {
+        bail!(
+            "Invalid save slot '{}': use up to {} letters, digits, '-' or '_'",
+            slot,
+            MAX_SLOT_LEN
+        );
+    }
+
+    Ok(())
+}
+
+fn save_key(slot: &str) -> Result<String> {
+    validate_slot(slot)?;
+
+    Ok(if slot == DEFAULT_SLOT {
+        SAVE_KEY.to_string()
+    } else {
+        format!("{}.{}", SAVE_KEY, slot)
+    })
+}
+
+fn backup_key(key: &str, backup: usize) -> String {
+    format!("{}{}{}", key, BACKUP_MARKER, backup)
+}
+
+/// Parse a save bundle, or the plain game state JSON older versions saved
+fn parse_save(save: &str) -> Result<GameEngine> {
+    match serde_json::from_str::<SaveBundle>(save) {
+        Ok(bundle) => bundle.into_engine(),
+        Err(_) => serde_json::from_str(save).context("Failed to parse game state"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::BTreeMap};
+
+    use super::*;
+    use crate::core::types::GameCommand;
+
+    #[derive(Default)]
+    struct MemoryStorage(RefCell<BTreeMap<String, String>>);
+
+    impl SaveStorage for &MemoryStorage {
+        fn read(&self, key: &str) -> Result<Option<String>> {
+            Ok(self.0.borrow().get(key).cloned())
+        }
+
+        fn write(&self, key: &str, value: &str) -> Result<()> {
+            self.0
+                .borrow_mut()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn remove(&self, key: &str) -> Result<()> {
+            self.0.borrow_mut().remove(key);
+            Ok(())
+        }
+
+        fn keys(&self) -> Result<Vec<String>> {
+            Ok(self.0.borrow().keys().cloned().collect())
+        }
+    }
+
+    fn next_day(engine: &mut GameEngine) {
+        let day = engine.get_day();
+        engine.execute(GameCommand::AdvanceDay);
+        assert_eq!(engine.get_day(), day + 1);
+    }
+
+    /// Days of a slot's backups, failing the test if one can't be read
+    fn backup_days(saves: &SaveSlots<&MemoryStorage>, slot: &str) -> Vec<u32> {
+        saves
+            .backups(slot)
+            .unwrap()
+            .into_iter()
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn saving_on_the_same_day_keeps_no_backup() {
+        let storage = MemoryStorage::default();
+        let saves = SaveSlots::new(&storage);
+        let engine = GameEngine::new_game("Tester");
+
+        saves.save(DEFAULT_SLOT, &engine).unwrap();
+        saves.save(DEFAULT_SLOT, &engine).unwrap();
+
+        assert!(saves.backups(DEFAULT_SLOT).unwrap().is_empty());
+        assert_eq!(saves.load(DEFAULT_SLOT).unwrap().unwrap().get_day(), 1);
+    }
+
+    #[test]
+    fn backups_rotate_newest_first_and_keep_the_last_few_days() {
+        let storage = MemoryStorage::default();
+        let saves = SaveSlots::new(&storage);
+        let mut engine = GameEngine::new_game("Tester");
+        let first_day = engine.get_day();
+
+        saves.save(DEFAULT_SLOT, &engine).unwrap();
+        for _ in 0..BACKUP_COUNT + 1 {
+            next_day(&mut engine);
+            saves.save(DEFAULT_SLOT, &engine).unwrap();
+        }
+
+        let last_day = engine.get_day();
+        let expected: Vec<u32> = (1..=BACKUP_COUNT as u32)
+            .map(|back| last_day - back)
+            .collect();
+        assert_eq!(backup_days(&saves, DEFAULT_SLOT), expected);
+        assert!(!expected.contains(&first_day));
+    }
+
+    #[test]
+    fn restoring_a_backup_backs_up_the_current_save() {
+        let storage = MemoryStorage::default();
+        let saves = SaveSlots::new(&storage);
+        let mut engine = GameEngine::new_game("Tester");
+        let first_day = engine.get_day();
+
+        saves.save("farm", &engine).unwrap();
+        next_day(&mut engine);
+        saves.save("farm", &engine).unwrap();
+
+        let restored = saves.restore_backup("farm", 1).unwrap();
+        assert_eq!(restored.get_day(), first_day);
+        assert_eq!(saves.load("farm").unwrap().unwrap().get_day(), first_day);
+        assert_eq!(backup_days(&saves, "farm")[0], engine.get_day());
+
+        assert!(saves.restore_backup("farm", 0).is_err());
+        assert!(saves.restore_backup("farm", BACKUP_COUNT + 1).is_err());
+    }
+
+    #[test]
+    fn slots_list_saves_but_not_backups() {
+        let storage = MemoryStorage::default();
+        let saves = SaveSlots::new(&storage);
+        let mut engine = GameEngine::new_game("Tester");
+
+        saves.save("spring", &engine).unwrap();
+        next_day(&mut engine);
+        saves.save("spring", &engine).unwrap();
+        saves.save(DEFAULT_SLOT, &engine).unwrap();
+
+        assert_eq!(saves.slots().unwrap(), [DEFAULT_SLOT, "spring"]);
+
+        saves.delete("spring").unwrap();
+        assert_eq!(saves.slots().unwrap(), [DEFAULT_SLOT]);
+        assert_eq!(saves.backups("spring").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn unreadable_backups_are_listed_in_place() {
+        let storage = MemoryStorage::default();
+        let saves = SaveSlots::new(&storage);
+        let mut engine = GameEngine::new_game("Tester");
+
+        saves.save(DEFAULT_SLOT, &engine).unwrap();
+        for _ in 0..2 {
+            next_day(&mut engine);
+            saves.save(DEFAULT_SLOT, &engine).unwrap();
+        }
+        (&storage)
+            .write(&backup_key(SAVE_KEY, 1), "not a save")
+            .unwrap();
+
+        let backups = saves.backups(DEFAULT_SLOT).unwrap();
+        assert_eq!(backups.len(), 2);
+        assert!(backups[0].is_err());
+        assert_eq!(backups[1].as_ref().unwrap(), &(engine.get_day() - 2));
+    }
+
+    #[test]
+    fn invalid_slot_names_are_refused() {
+        let storage = MemoryStorage::default();
+        let saves = SaveSlots::new(&storage);
+        let engine = GameEngine::new_game("Tester");
+
+        for slot in ["", "../escape", "has space", &"x".repeat(MAX_SLOT_LEN + 1)] {
+            assert!(saves.save(slot, &engine).is_err(), "slot {:?}", slot);
+        }
+    }
+
+    #[test]
+    fn legacy_plain_saves_still_load() {
+        let storage = MemoryStorage::default();
+        let engine = GameEngine::new_game("Tester");
+        let legacy = serde_json::to_string(&engine).unwrap();
+        (&storage).write(SAVE_KEY, &legacy).unwrap();
+
+        let loaded = SaveSlots::new(&storage)
+            .load(DEFAULT_SLOT)
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.get_player().name, "Tester");
+    }
+}
@@ -1,8 +1,20 @@
-use anyhow::{Result, bail};
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result, bail};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::crop::{Crop, initiate_starter_crops};
+use super::{
+    animal::{Animal, BUILDING_CAPACITY, BuildingKind, FEED_ITEMS},
+    crafting::{CraftingJob, Recipe, item_sell_price},
+    crop::{Crop, initiate_starter_crops},
+    farm::{FARM_PLOTS, GIANT_CROP_YIELD, footprint, plot_map},
+    machine::{Machine, Processing, find_machine, find_product},
+    skill::{HARVEST_XP, PLANT_XP, Skills, WATER_XP},
+    soil::{Plot, find_fertilizer},
+    sprinkler::{Sprinkler, find_sprinkler},
+};
 
 const STARTING_MONEY: u32 = 1000;
 
@@ -14,6 +26,43 @@ pub struct Player {
     pub energy: u8,
     pub inventory: Vec<Crop>,
     pub fields: Vec<Crop>,
+    /// Soil state of every farm plot
+    #[serde(default = "default_plots")]
+    pub plots: Vec<Plot>,
+    /// Non-crop items such as fertilizer, counted by name
+    #[serde(default)]
+    pub items: BTreeMap<String, u32>,
+    #[serde(default)]
+    pub sprinklers: Vec<Sprinkler>,
+    /// Items being crafted
+    #[serde(default)]
+    pub crafting: Vec<CraftingJob>,
+    #[serde(default)]
+    pub machines: Vec<Machine>,
+    /// Coops and barns on the farm
+    #[serde(default)]
+    pub buildings: Vec<BuildingKind>,
+    #[serde(default)]
+    pub animals: Vec<Animal>,
+    #[serde(default)]
+    pub skills: Skills,
+}
+
+fn default_plots() -> Vec<Plot> {
+    vec![Plot::default(); FARM_PLOTS]
+}
+
+/// Record a day of watering, judging the crop's quality once it is fully grown
+fn water_crop(crop: &mut Crop, plots: &[Plot], current_day: u32) {
+    crop.watered_days.push(current_day);
+
+    if crop.watered_days.len() as u8 >= crop.days_to_mature() {
+        crop.ready_harvest = true;
+
+        if let Some(soil) = crop.plot.and_then(|plot| plots.get(plot)) {
+            crop.quality = soil.crop_quality(crop.rotation_bonus);
+        }
+    }
 }
 
 impl Player {
@@ -29,72 +78,626 @@ impl Player {
             energy: Self::MAX_ENERGY,
             inventory: starter_crops,
             fields: Vec::new(),
+            plots: default_plots(),
+            items: BTreeMap::new(),
+            sprinklers: Vec::new(),
+            crafting: Vec::new(),
+            machines: Vec::new(),
+            buildings: Vec::new(),
+            animals: Vec::new(),
+            skills: Skills::default(),
+        }
+    }
+
+    /// Max energy, raised by farming levels
+    pub fn max_energy(&self) -> u8 {
+        Self::MAX_ENERGY + self.skills.max_energy_bonus()
+    }
+
+    /// Energy it takes to plant or water the crop at the player's farming level
+    pub fn energy_cost(&self, crop: &Crop) -> u8 {
+        self.skills.energy_cost(crop.energy_cost)
+    }
+
+    /// Number of animals the player's buildings of this kind can house
+    pub fn housing_capacity(&self, building: BuildingKind) -> usize {
+        self.buildings.iter().filter(|b| **b == building).count() * BUILDING_CAPACITY
+    }
+
+    /// Move an animal into its housing, failing if there is no room
+    pub fn add_animal(&mut self, animal: Animal) -> Result<()> {
+        let housing = animal.kind.housing();
+        let housed = self
+            .animals
+            .iter()
+            .filter(|a| a.kind.housing() == housing)
+            .count();
+
+        if housed >= self.housing_capacity(housing) {
+            bail!(
+                "No room for a {}. Build another {} first",
+                animal.kind.name(),
+                housing.name()
+            );
         }
+
+        self.animals.push(animal);
+        Ok(())
+    }
+
+    pub fn remove_animal(&mut self, id: Uuid) -> Result<Animal> {
+        let index = self
+            .animals
+            .iter()
+            .position(|animal| animal.id == id)
+            .context("Animal not found")?;
+
+        Ok(self.animals.remove(index))
+    }
+
+    /// Feed every hungry animal from the feed in the inventory
+    pub fn feed_animals(&mut self) -> Result<Feeding> {
+        if self.animals.is_empty() {
+            bail!("You don't have any animals");
+        }
+
+        let mut feeding = Feeding::default();
+
+        for index in 0..self.animals.len() {
+            if self.animals[index].fed_today {
+                continue;
+            }
+
+            let Some(feed) = FEED_ITEMS
+                .iter()
+                .find(|feed| self.items.contains_key(**feed))
+            else {
+                feeding.hungry.push(self.animals[index].name.clone());
+                continue;
+            };
+
+            self.take_item(feed, 1)?;
+            *feeding.feed_used.entry(feed.to_string()).or_insert(0) += 1;
+            self.animals[index].fed_today = true;
+            feeding.fed += 1;
+        }
+
+        if feeding.fed == 0 {
+            if feeding.hungry.is_empty() {
+                bail!("Your animals have already eaten today");
+            }
+            bail!("You have no feed ({})", FEED_ITEMS.join(", "));
+        }
+
+        Ok(feeding)
+    }
+
+    /// Wake the animals up and gather what they produced overnight
+    pub fn tend_animals(&mut self) -> (BTreeMap<String, u32>, Vec<String>) {
+        let mut products = BTreeMap::new();
+        let mut hungry = Vec::new();
+
+        for animal in &mut self.animals {
+            match animal.wake_up() {
+                0 => hungry.push(animal.name.clone()),
+                count => {
+                    *products
+                        .entry(animal.kind.product().to_string())
+                        .or_insert(0) += count
+                }
+            }
+        }
+
+        for (name, count) in &products {
+            self.add_item(name, *count);
+        }
+
+        (products, hungry)
+    }
+
+    pub fn item_count(&self, name: &str) -> u32 {
+        self.items.get(name).copied().unwrap_or(0)
+    }
+
+    /// Add items to the inventory, stopping at the most a stack can hold
+    pub fn add_item(&mut self, name: &str, count: u32) {
+        let owned = self.items.entry(name.to_string()).or_insert(0);
+        *owned = owned.saturating_add(count);
+    }
+
+    /// Remove items from the inventory, failing if there are not enough
+    pub fn take_item(&mut self, name: &str, count: u32) -> Result<()> {
+        let owned = self.item_count(name);
+        if owned < count {
+            bail!("Not enough {} (have {}, need {})", name, owned, count);
+        }
+
+        if owned == count {
+            self.items.remove(name);
+        } else {
+            self.items.insert(name.to_string(), owned - count);
+        }
+
+        Ok(())
+    }
+
+    /// Spread fertilizer from the inventory on a plot
+    pub fn fertilize(&mut self, plot: usize, fertilizer_name: &str) -> Result<u8> {
+        let fertilizer = find_fertilizer(fertilizer_name)
+            .with_context(|| format!("{} is not a fertilizer", fertilizer_name))?;
+
+        if plot >= self.plots.len() {
+            bail!("Plot {} is not on the farm", plot + 1);
+        }
+
+        self.take_item(&fertilizer.name, 1)?;
+        self.plots[plot].fertilize(fertilizer.fertility);
+
+        Ok(self.plots[plot].fertility)
     }
 
     pub fn sleep(&mut self) {
-        self.energy = Self::MAX_ENERGY;
+        self.energy = self.max_energy();
     }
 
-    pub fn plant_crop(&mut self, crop: Crop) -> Result<()> {
-        if self.energy < crop.energy_cost {
+    /// Plant a crop in the given plot, or the first free plot
+    pub fn plant_crop(&mut self, mut crop: Crop, plot: Option<usize>) -> Result<usize> {
+        let energy_cost = self.energy_cost(&crop);
+        if self.energy < energy_cost {
             bail!("Not enough energy to plant the crop");
         }
 
+        let plot = match plot {
+            Some(plot) => {
+                self.check_plot_free(plot)?;
+                plot
+            }
+            None => self
+                .first_free_plot()
+                .context("No free plots on the farm")?,
+        };
+        if plot >= self.plots.len() {
+            bail!("Plot {} is not on the farm", plot + 1);
+        }
+
         self.inventory.retain(|c| c.id != crop.id);
-        self.energy = self.energy.saturating_sub(crop.energy_cost).max(0);
+        self.energy = self.energy.saturating_sub(energy_cost);
+        self.skills.gain_farming_xp(PLANT_XP);
+
+        let soil = &mut self.plots[plot];
+        let family = crop.family();
+        crop.plot = Some(plot);
+        crop.soil_modifier = soil.growth_modifier();
+        crop.rotation_bonus = soil.last_family.is_some_and(|last| last != family);
+        soil.last_family = Some(family);
+
         self.fields.push(crop);
 
+        Ok(plot)
+    }
+
+    /// Fail unless the plot is on the farm and holds neither a crop nor a sprinkler
+    fn check_plot_free(&self, plot: usize) -> Result<()> {
+        if plot >= FARM_PLOTS {
+            bail!("Plot {} is not on the farm", plot + 1);
+        }
+        if plot_map(&self.fields)[plot].is_some() {
+            bail!("Plot {} is already planted", plot + 1);
+        }
+        if self.sprinkler_at(plot).is_some() {
+            bail!("Plot {} has a sprinkler on it", plot + 1);
+        }
+        if self.machine_at(plot).is_some() {
+            bail!("Plot {} has a machine on it", plot + 1);
+        }
+
         Ok(())
     }
 
-    pub fn water_crops(&mut self, current_day: u32) -> Result<()> {
+    pub fn first_free_plot(&self) -> Option<usize> {
+        (0..FARM_PLOTS).find(|&plot| self.check_plot_free(plot).is_ok())
+    }
+
+    /// Place crops from saves made before the farm had a layout, or left off the farm
+    pub fn assign_missing_plots(&mut self) {
+        for index in 0..self.fields.len() {
+            if self.fields[index]
+                .plot
+                .is_none_or(|plot| plot >= FARM_PLOTS)
+            {
+                self.fields[index].plot = self.first_free_plot();
+            }
+        }
+    }
+
+    pub fn sprinkler_at(&self, plot: usize) -> Option<&Sprinkler> {
+        self.sprinklers
+            .iter()
+            .find(|sprinkler| sprinkler.plot == plot)
+    }
+
+    /// Place a sprinkler from the inventory on an empty plot
+    pub fn place_sprinkler(&mut self, plot: usize, sprinkler_name: &str) -> Result<()> {
+        let kind = find_sprinkler(sprinkler_name)
+            .with_context(|| format!("{} is not a sprinkler", sprinkler_name))?;
+
+        self.check_plot_free(plot)?;
+        self.take_item(kind.name(), 1)?;
+        self.sprinklers.push(Sprinkler { kind, plot });
+
+        Ok(())
+    }
+
+    /// Pick up a sprinkler and put it back in the inventory
+    pub fn remove_sprinkler(&mut self, plot: usize) -> Result<Sprinkler> {
+        let index = self
+            .sprinklers
+            .iter()
+            .position(|sprinkler| sprinkler.plot == plot)
+            .with_context(|| format!("There is no sprinkler on plot {}", plot + 1))?;
+
+        let sprinkler = self.sprinklers.remove(index);
+        self.add_item(sprinkler.kind.name(), 1);
+
+        Ok(sprinkler)
+    }
+
+    pub fn machine_at(&self, plot: usize) -> Option<&Machine> {
+        self.machines.iter().find(|machine| machine.plot == plot)
+    }
+
+    /// Place a machine from the inventory on an empty plot
+    pub fn place_machine(&mut self, plot: usize, machine_name: &str) -> Result<()> {
+        let kind = find_machine(machine_name)
+            .with_context(|| format!("{} is not a machine", machine_name))?;
+
+        self.check_plot_free(plot)?;
+        self.take_item(kind.name(), 1)?;
+        self.machines.push(Machine {
+            kind,
+            plot,
+            processing: None,
+        });
+
+        Ok(())
+    }
+
+    /// Pick up an idle machine and put it back in the inventory
+    pub fn remove_machine(&mut self, plot: usize) -> Result<Machine> {
+        let index = self
+            .machines
+            .iter()
+            .position(|machine| machine.plot == plot)
+            .with_context(|| format!("There is no machine on plot {}", plot + 1))?;
+
+        if self.machines[index].processing.is_some() {
+            bail!(
+                "The {} on plot {} is still in use",
+                self.machines[index].kind.name(),
+                plot + 1
+            );
+        }
+
+        let machine = self.machines.remove(index);
+        self.add_item(machine.kind.name(), 1);
+
+        Ok(machine)
+    }
+
+    /// Put an item from the inventory into an idle machine
+    pub fn load_machine(
+        &mut self,
+        plot: usize,
+        item_name: &str,
+        current_day: u32,
+    ) -> Result<Processing> {
+        let machine = self
+            .machine_at(plot)
+            .with_context(|| format!("There is no machine on plot {}", plot + 1))?;
+
+        if machine.processing.is_some() {
+            bail!("The {} on plot {} is busy", machine.kind.name(), plot + 1);
+        }
+
+        let product = find_product(machine.kind, item_name)
+            .with_context(|| format!("A {} can't process {}", machine.kind.name(), item_name))?;
+
+        self.take_item(item_name, 1)?;
+
+        let processing = Processing {
+            input: product.input,
+            output: product.output,
+            ready_day: current_day + product.days,
+            ready: false,
+        };
+
+        if let Some(machine) = self.machines.iter_mut().find(|m| m.plot == plot) {
+            machine.processing = Some(processing.clone());
+        }
+
+        Ok(processing)
+    }
+
+    /// Mark machine products as ready, returning the ones finished today
+    pub fn run_machines(&mut self, current_day: u32) -> Vec<String> {
+        self.machines
+            .iter_mut()
+            .filter_map(|machine| machine.processing.as_mut())
+            .filter(|processing| !processing.ready && processing.ready_day <= current_day)
+            .map(|processing| {
+                processing.ready = true;
+                processing.output.clone()
+            })
+            .collect()
+    }
+
+    /// Take every finished product out of the machines
+    pub fn collect_machine_products(&mut self) -> Result<Vec<String>> {
+        let mut collected = Vec::new();
+
+        for machine in &mut self.machines {
+            if let Some(processing) = machine.processing.take_if(|processing| processing.ready) {
+                collected.push(processing.output);
+            }
+        }
+
+        if collected.is_empty() {
+            bail!("No machine products are ready");
+        }
+
+        for output in &collected {
+            self.add_item(output, 1);
+        }
+
+        Ok(collected)
+    }
+
+    /// Water every growing crop, returning how many crops were watered
+    pub fn water_crops(&mut self, current_day: u32) -> Result<usize> {
         if self.fields.is_empty() {
             bail!("No crops to water");
         }
 
+        let mut watered = 0;
+
         for crop in &mut self.fields {
-            if crop.ready_harvest || crop.watered_days.contains(&current_day) {
+            if !crop.needs_water(current_day) {
                 continue;
             }
 
-            if self.energy < crop.energy_cost {
+            let energy_cost = self.skills.energy_cost(crop.energy_cost);
+            if self.energy < energy_cost {
                 bail!("Not enough energy to water {}", crop.name);
             }
 
-            crop.watered_days.push(current_day);
-            self.energy = self.energy.saturating_sub(crop.energy_cost).max(0);
+            self.energy = self.energy.saturating_sub(energy_cost);
+            water_crop(crop, &self.plots, current_day);
+            self.skills.gain_farming_xp(WATER_XP);
+            watered += 1;
+        }
+
+        Ok(watered)
+    }
+
+    /// Water the crops covered by sprinklers, returning their names
+    pub fn run_sprinklers(&mut self, current_day: u32) -> Vec<String> {
+        let covered: Vec<usize> = self
+            .sprinklers
+            .iter()
+            .flat_map(Sprinkler::covered_plots)
+            .collect();
+        let mut watered = Vec::new();
 
-            if crop.watered_days.len() as u8 == crop.growth_days {
-                crop.ready_harvest = true;
+        for crop in &mut self.fields {
+            let in_range = footprint(crop).iter().any(|plot| covered.contains(plot));
+
+            if in_range && crop.needs_water(current_day) {
+                water_crop(crop, &self.plots, current_day);
+                watered.push(crop.name.clone());
             }
         }
 
-        Ok(())
+        watered
     }
 
-    pub fn harvest_crops(&mut self) -> Result<u32> {
+    /// Harvest every ready crop. Regrowing crops stay in the field.
+    ///
+    /// With `keep`, produce is stored as items instead of being sold, except for
+    /// crops the market doesn't know how to price.
+    pub fn harvest_crops(
+        &mut self,
+        current_day: u32,
+        keep: bool,
+        rng: &mut impl Rng,
+    ) -> Result<Harvest> {
         if self.fields.is_empty() {
             bail!("No crops to harvest");
         }
 
-        let mut total_earnings = 0;
+        let mut harvest = Harvest::default();
+
+        for crop in &mut self.fields {
+            if !crop.ready_harvest {
+                continue;
+            }
+
+            for plot in footprint(crop) {
+                if let Some(soil) = self.plots.get_mut(plot) {
+                    soil.deplete();
+                }
+            }
+
+            let mut units = 1;
+
+            if crop.giant {
+                units = GIANT_CROP_YIELD;
+            } else if crop.extra_yield_chance > 0.0 && rng.random::<f32>() < crop.extra_yield_chance
+            {
+                units += 1;
+                harvest.extra_yields += 1;
+            }
+
+            if keep && item_sell_price(&crop.name).is_some() {
+                *harvest.produce.entry(crop.name.clone()).or_insert(0) += units;
+                // Stored produce sells at the base price, so quality is paid for now
+                harvest.quality_bonus += (crop.harvest_value() - crop.sell_price) * units;
+            } else {
+                harvest.earnings += crop.harvest_value() * units;
+            }
 
-        let mut harvested_crops = Vec::new();
-        for crop in &self.fields {
-            if crop.ready_harvest {
-                total_earnings += crop.sell_price;
-                harvested_crops.push(crop.id);
+            if crop.regrow(current_day) {
+                harvest.regrowing.push(crop.name.clone());
+            } else {
+                harvest.harvested.push(crop.id);
             }
         }
 
-        if total_earnings == 0 {
+        if harvest.harvested.is_empty() && harvest.regrowing.is_empty() {
             bail!("No crops are ready for harvest");
         }
 
-        self.fields.retain(|c| !harvested_crops.contains(&c.id));
-        self.money += total_earnings;
+        self.fields.retain(|c| !harvest.harvested.contains(&c.id));
+        self.money += harvest.earnings + harvest.quality_bonus;
+        self.skills.gain_farming_xp(
+            (harvest.harvested.len() + harvest.regrowing.len()) as u32 * HARVEST_XP,
+        );
+
+        for (name, count) in &harvest.produce {
+            self.add_item(name, *count);
+        }
+
+        Ok(harvest)
+    }
+
+    /// Sell items from the inventory, returning the coins earned
+    pub fn sell_item(&mut self, name: &str, quantity: u32) -> Result<u32> {
+        let price = item_sell_price(name).with_context(|| format!("{} can't be sold", name))?;
+
+        if quantity == 0 {
+            bail!("Nothing to sell");
+        }
+
+        self.take_item(name, quantity)?;
+
+        let earnings = price * quantity;
+        self.money += earnings;
+
+        Ok(earnings)
+    }
+
+    /// Consume a recipe's ingredients and start crafting its output
+    pub fn craft(&mut self, recipe: &Recipe, current_day: u32) -> Result<CraftingJob> {
+        if self.skills.farming_level() < recipe.level {
+            bail!(
+                "Reach farming level {} to craft {}",
+                recipe.level,
+                recipe.name
+            );
+        }
+
+        for (item, count) in &recipe.ingredients {
+            let owned = self.item_count(item);
+            if owned < *count {
+                bail!(
+                    "Not enough {} for {} (have {}, need {})",
+                    item,
+                    recipe.name,
+                    owned,
+                    count
+                );
+            }
+        }
+
+        for (item, count) in &recipe.ingredients {
+            self.take_item(item, *count)?;
+        }
+
+        let job = CraftingJob {
+            recipe_id: recipe.id.clone(),
+            output: recipe.output.clone(),
+            ready_day: current_day + recipe.days,
+        };
+        self.crafting.push(job.clone());
+
+        Ok(job)
+    }
+
+    /// Collect every crafted item that is ready, returning their names
+    pub fn finish_crafting(&mut self, current_day: u32) -> Vec<String> {
+        let (ready, pending) = self
+            .crafting
+            .drain(..)
+            .partition(|job| job.ready_day <= current_day);
+        self.crafting = pending;
+
+        ready
+            .into_iter()
+            .map(|job: CraftingJob| {
+                self.add_item(&job.output, 1);
+                job.output
+            })
+            .collect()
+    }
+}
+
+/// Result of feeding the animals
+#[derive(Debug, Default)]
+pub struct Feeding {
+    pub fed: u32,
+    pub feed_used: BTreeMap<String, u32>,
+    /// Names of animals left hungry for lack of feed
+    pub hungry: Vec<String>,
+}
+
+/// Result of harvesting the fields
+#[derive(Debug, Default)]
+pub struct Harvest {
+    pub earnings: u32,
+    /// Produce kept as items, by crop name
+    pub produce: BTreeMap<String, u32>,
+    /// Coins paid on top of the base price for kept produce of high quality
+    pub quality_bonus: u32,
+    pub extra_yields: u32,
+    /// Crops removed from the fields
+    pub harvested: Vec<Uuid>,
+    /// Names of crops left in the fields to produce again
+    pub regrowing: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::core::season::Season;
+
+    fn parsnip() -> Crop {
+        Crop::new("Parsnip", 4, 35, vec![Season::Spring], "🥕")
+    }
+
+    #[test]
+    fn planting_past_a_short_plots_list_is_refused() {
+        let mut player = Player::new("Tester");
+        player.plots.truncate(2);
+        let fields = player.fields.len();
+
+        assert!(player.plant_crop(parsnip(), Some(5)).is_err());
+        assert_eq!(player.fields.len(), fields);
+        assert_eq!(player.plant_crop(parsnip(), Some(1)).unwrap(), 1);
+    }
+
+    #[test]
+    fn harvesting_past_a_short_plots_list_does_not_panic() {
+        let mut player = Player::new("Tester");
+        player.plots.truncate(2);
+
+        let mut crop = parsnip();
+        crop.plot = Some(5);
+        crop.ready_harvest = true;
+        player.fields = vec![crop];
 
-        Ok(total_earnings)
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(player.harvest_crops(1, false, &mut rng).is_ok());
+        assert!(player.fields.is_empty());
     }
 }
@@ -0,0 +1,114 @@
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::game_engine::GameEngine;
+
+/// Current version of the portable save bundle format
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// Portable save bundle shared by the CLI and the web build.
+///
+/// The game state is stored as a JSON value so the checksum can be verified
+/// independently of the engine version that reads it back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveBundle {
+    pub format_version: u32,
+    pub game_version: String,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+    pub checksum: String,
+    pub state: serde_json::Value,
+}
+
+impl SaveBundle {
+    /// Build a bundle from the current game state
+    pub fn from_engine(engine: &GameEngine) -> Result<Self> {
+        let state = serde_json::to_value(engine).context("Failed to serialize game state")?;
+        let checksum = Self::compute_checksum(&state)?;
+
+        Ok(Self {
+            format_version: BUNDLE_FORMAT_VERSION,
+            game_version: env!("CARGO_PKG_VERSION").to_string(),
+            exported_at: chrono::Utc::now(),
+            checksum,
+            state,
+        })
+    }
+
+    /// Verify the bundle and restore the game engine it contains
+    pub fn into_engine(self) -> Result<GameEngine> {
+        if self.format_version > BUNDLE_FORMAT_VERSION {
+            bail!(
+                "Save bundle format version {} is newer than supported version {}",
+                self.format_version,
+                BUNDLE_FORMAT_VERSION
+            );
+        }
+
+        let checksum = Self::compute_checksum(&self.state)?;
+        if checksum != self.checksum {
+            bail!("Save bundle checksum mismatch, the file may be corrupted");
+        }
+
+        serde_json::from_value(self.state).context("Failed to parse game state from save bundle")
+    }
+
+    /// Serialize a game engine into a bundle JSON string
+    pub fn export(engine: &GameEngine) -> Result<String> {
+        let bundle = Self::from_engine(engine)?;
+        serde_json::to_string_pretty(&bundle).context("Failed to serialize save bundle")
+    }
+
+    /// Parse a bundle JSON string and restore the game engine
+    pub fn import(json: &str) -> Result<GameEngine> {
+        let bundle: SaveBundle =
+            serde_json::from_str(json).context("Failed to parse save bundle")?;
+        bundle.into_engine()
+    }
+
+    fn compute_checksum(state: &serde_json::Value) -> Result<String> {
+        // serde_json::Value keeps object keys sorted, so this encoding is stable
+        let bytes = serde_json::to_vec(state).context("Failed to encode game state")?;
+        let digest = Sha256::digest(&bytes);
+
+        Ok(digest.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_then_import_restores_the_game() {
+        let engine = GameEngine::new_game("Tester");
+
+        let json = SaveBundle::export(&engine).unwrap();
+        let restored = SaveBundle::import(&json).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&restored).unwrap(),
+            serde_json::to_value(&engine).unwrap()
+        );
+    }
+
+    #[test]
+    fn tampered_state_is_rejected() {
+        let mut bundle = SaveBundle::from_engine(&GameEngine::new_game("Tester")).unwrap();
+        bundle.state["player"]["money"] = serde_json::json!(999_999);
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        let error = SaveBundle::import(&json).unwrap_err();
+        assert!(error.to_string().contains("checksum mismatch"));
+    }
+
+    #[test]
+    fn newer_format_version_is_rejected() {
+        let mut bundle = SaveBundle::from_engine(&GameEngine::new_game("Tester")).unwrap();
+        bundle.format_version = BUNDLE_FORMAT_VERSION + 1;
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        let error = SaveBundle::import(&json).unwrap_err();
+        assert!(error.to_string().contains("newer than supported"));
+    }
+}
@@ -1,9 +1,11 @@
+use anyhow::{Result, bail};
 use serde::{Deserialize, Serialize};
 
-const DAYS_PER_SEASON: u32 = 10;
-const DAYS_PER_YEAR: u32 = DAYS_PER_SEASON * 4;
+/// Days in each season unless the player picks another length
+pub const DEFAULT_DAYS_PER_SEASON: u32 = 10;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub enum Season {
     Spring,
     Summer,
@@ -12,35 +14,6 @@ pub enum Season {
 }
 
 impl Season {
-    pub fn from_day(day: u32) -> Self {
-        let year_day = ((day - 1) % (DAYS_PER_SEASON * 4)) + 1;
-
-        match year_day {
-            d if d <= DAYS_PER_SEASON => Season::Spring,
-            d if d <= DAYS_PER_SEASON * 2 => Season::Summer,
-            d if d <= DAYS_PER_SEASON * 3 => Season::Autumn,
-            _ => Season::Winter,
-        }
-    }
-
-    pub fn day_in_season(day: u32) -> u32 {
-        let year_day = ((day - 1) % (DAYS_PER_SEASON * 4)) + 1;
-
-        if year_day <= DAYS_PER_SEASON {
-            year_day
-        } else if year_day <= DAYS_PER_SEASON * 2 {
-            year_day - DAYS_PER_SEASON
-        } else if year_day <= DAYS_PER_SEASON * 3 {
-            year_day - DAYS_PER_SEASON * 2
-        } else {
-            year_day - DAYS_PER_SEASON * 3
-        }
-    }
-
-    pub fn year(day: u32) -> u32 {
-        ((day - 1) / DAYS_PER_YEAR) + 1
-    }
-
     pub fn icon(&self) -> &str {
         match self {
             Season::Spring => "🌸",
@@ -65,3 +38,118 @@ impl std::fmt::Display for Season {
         write!(f, "{} {}", self.icon(), self.name())
     }
 }
+
+/// Calendar used by a game: how long seasons are and which seasons make up a year
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+#[serde(try_from = "CalendarConfig")]
+pub struct Calendar {
+    pub days_per_season: u32,
+    pub seasons: Vec<Season>,
+    pub starting_season: Season,
+}
+
+/// A calendar as saved, checked by `Calendar::new` before use
+#[derive(Deserialize)]
+struct CalendarConfig {
+    days_per_season: u32,
+    seasons: Vec<Season>,
+    starting_season: Season,
+}
+
+impl TryFrom<CalendarConfig> for Calendar {
+    type Error = anyhow::Error;
+
+    fn try_from(config: CalendarConfig) -> Result<Self> {
+        Calendar::new(
+            config.days_per_season,
+            config.seasons,
+            config.starting_season,
+        )
+    }
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self {
+            days_per_season: DEFAULT_DAYS_PER_SEASON,
+            seasons: vec![
+                Season::Spring,
+                Season::Summer,
+                Season::Autumn,
+                Season::Winter,
+            ],
+            starting_season: Season::Spring,
+        }
+    }
+}
+
+impl Calendar {
+    pub fn new(
+        days_per_season: u32,
+        seasons: Vec<Season>,
+        starting_season: Season,
+    ) -> Result<Self> {
+        if days_per_season == 0 {
+            bail!("A season must last at least one day");
+        }
+
+        if seasons.is_empty() {
+            bail!("The calendar needs at least one season");
+        }
+
+        if !seasons.contains(&starting_season) {
+            bail!(
+                "Starting season {} is not part of the calendar",
+                starting_season.name()
+            );
+        }
+
+        Ok(Self {
+            days_per_season,
+            seasons,
+            starting_season,
+        })
+    }
+
+    pub fn days_per_year(&self) -> u32 {
+        self.days_per_season * self.seasons.len() as u32
+    }
+
+    pub fn season_for_day(&self, day: u32) -> Season {
+        self.seasons[self.season_index(day) % self.seasons.len()]
+    }
+
+    pub fn day_in_season(&self, day: u32) -> u32 {
+        (self.offset_day(day) % self.days_per_season) + 1
+    }
+
+    pub fn year(&self, day: u32) -> u32 {
+        ((day - 1) / self.days_per_year()) + 1
+    }
+
+    /// Days left before the season changes, counting the given day
+    pub fn days_until_season_end(&self, day: u32) -> u32 {
+        self.days_per_season - self.day_in_season(day) + 1
+    }
+
+    /// Season that follows the one the given day is in
+    pub fn next_season(&self, day: u32) -> Season {
+        self.season_for_day(day + self.days_until_season_end(day))
+    }
+
+    /// Zero-based day counted from the start of the first season of the year
+    fn offset_day(&self, day: u32) -> u32 {
+        let start_index = self
+            .seasons
+            .iter()
+            .position(|season| *season == self.starting_season)
+            .unwrap_or(0) as u32;
+
+        (day - 1) + start_index * self.days_per_season
+    }
+
+    fn season_index(&self, day: u32) -> usize {
+        (self.offset_day(day) / self.days_per_season) as usize
+    }
+}
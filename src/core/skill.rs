@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use super::crafting::recipe_catalog;
+
+/// Farming XP for planting a crop
+pub const PLANT_XP: u32 = 5;
+/// Farming XP for each crop watered by hand
+pub const WATER_XP: u32 = 2;
+/// Farming XP for each crop harvested
+pub const HARVEST_XP: u32 = 10;
+
+/// Total XP needed to reach each level after the first
+const LEVEL_XP: [u32; 10] = [100, 250, 450, 700, 1000, 1400, 1900, 2500, 3200, 4000];
+pub const MAX_LEVEL: u8 = LEVEL_XP.len() as u8;
+
+/// Energy saved per task for every farming level
+pub const ENERGY_DISCOUNT_PER_LEVEL: u8 = 1;
+/// Tasks never cost less than this, however skilled the farmer
+pub const MIN_ENERGY_COST: u8 = 5;
+/// Max energy gained for every farming level
+pub const MAX_ENERGY_PER_LEVEL: u8 = 5;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Skills {
+    pub farming_xp: u32,
+}
+
+impl Skills {
+    pub fn farming_level(&self) -> u8 {
+        LEVEL_XP.iter().filter(|xp| self.farming_xp >= **xp).count() as u8
+    }
+
+    /// Total XP needed for the next farming level, or `None` at the max level
+    pub fn next_level_xp(&self) -> Option<u32> {
+        LEVEL_XP.get(self.farming_level() as usize).copied()
+    }
+
+    /// Add farming XP, returning the new level if the player leveled up
+    pub fn gain_farming_xp(&mut self, xp: u32) -> Option<u8> {
+        let level = self.farming_level();
+        self.farming_xp += xp;
+
+        let new_level = self.farming_level();
+        (new_level > level).then_some(new_level)
+    }
+
+    /// Energy saved on every planting and watering task
+    pub fn energy_discount(&self) -> u8 {
+        self.farming_level() * ENERGY_DISCOUNT_PER_LEVEL
+    }
+
+    /// Energy a task with the given base cost takes at the current level
+    pub fn energy_cost(&self, base: u8) -> u8 {
+        base.saturating_sub(self.energy_discount())
+            .max(MIN_ENERGY_COST.min(base))
+    }
+
+    pub fn max_energy_bonus(&self) -> u8 {
+        self.farming_level() * MAX_ENERGY_PER_LEVEL
+    }
+}
+
+/// Perks gained on reaching a farming level
+pub fn level_perks(level: u8) -> Vec<String> {
+    let mut perks = vec![
+        format!(
+            "Planting and watering cost {} less energy",
+            ENERGY_DISCOUNT_PER_LEVEL
+        ),
+        format!("+{} max energy", MAX_ENERGY_PER_LEVEL),
+    ];
+
+    perks.extend(
+        recipe_catalog()
+            .into_iter()
+            .filter(|recipe| recipe.level == level)
+            .map(|recipe| format!("New recipe: {} {}", recipe.icon, recipe.name)),
+    );
+
+    perks
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use super::crop::{CropFamily, CropQuality};
+
+/// Fertility of untouched soil, too low for better than normal quality crops
+pub const DEFAULT_FERTILITY: u8 = 50;
+/// Highest possible soil fertility
+pub const MAX_FERTILITY: u8 = 100;
+/// Fertility lost by a plot each time a crop is harvested from it
+pub const HARVEST_DEPLETION: u8 = 15;
+/// Fertility from which crops grow one day faster
+pub const RICH_SOIL_FERTILITY: u8 = 80;
+/// Fertility below which crops grow one day slower
+pub const POOR_SOIL_FERTILITY: u8 = 30;
+/// Quality points gained by planting a different crop family than the last one
+pub const ROTATION_BONUS: u8 = 20;
+
+/// Soil state of a single farm plot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Plot {
+    pub fertility: u8,
+    /// Family of the last crop planted here, used for crop rotation
+    pub last_family: Option<CropFamily>,
+}
+
+impl Default for Plot {
+    fn default() -> Self {
+        Self {
+            fertility: DEFAULT_FERTILITY,
+            last_family: None,
+        }
+    }
+}
+
+impl Plot {
+    pub fn deplete(&mut self) {
+        self.fertility = self.fertility.saturating_sub(HARVEST_DEPLETION);
+    }
+
+    pub fn fertilize(&mut self, amount: u8) {
+        self.fertility = self.fertility.saturating_add(amount).min(MAX_FERTILITY);
+    }
+
+    /// Days added to (or removed from) a crop's growth time when planted here
+    pub fn growth_modifier(&self) -> i8 {
+        if self.fertility >= RICH_SOIL_FERTILITY {
+            -1
+        } else if self.fertility < POOR_SOIL_FERTILITY {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Quality of a crop maturing in this plot
+    pub fn crop_quality(&self, rotation_bonus: bool) -> CropQuality {
+        let bonus = if rotation_bonus { ROTATION_BONUS } else { 0 };
+
+        match self.fertility.saturating_add(bonus) {
+            score if score >= 90 => CropQuality::Gold,
+            score if score >= 60 => CropQuality::Silver,
+            _ => CropQuality::Normal,
+        }
+    }
+}
+
+/// A fertilizer sold in the shop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Fertilizer {
+    pub name: String,
+    pub icon: String,
+    pub price: u32,
+    pub fertility: u8,
+}
+
+impl Fertilizer {
+    fn new(name: &str, icon: &str, price: u32, fertility: u8) -> Self {
+        Self {
+            name: name.to_string(),
+            icon: icon.to_string(),
+            price,
+            fertility,
+        }
+    }
+}
+
+pub fn fertilizer_catalog() -> Vec<Fertilizer> {
+    vec![
+        Fertilizer::new("Basic Fertilizer", "🟤", 50, 30),
+        Fertilizer::new("Deluxe Fertilizer", "🟣", 150, 60),
+    ]
+}
+
+pub fn find_fertilizer(name: &str) -> Option<Fertilizer> {
+    fertilizer_catalog()
+        .into_iter()
+        .find(|fertilizer| fertilizer.name == name)
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use super::farm::{neighbors, plot_at, plot_coords};
+
+/// Types of sprinklers, from cheapest to widest range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum SprinklerKind {
+    /// Waters the four plots sharing an edge with it
+    Basic,
+    /// Waters the eight plots around it
+    Quality,
+    /// Waters every plot within two plots of it
+    Iridium,
+}
+
+impl SprinklerKind {
+    pub fn name(&self) -> &str {
+        match self {
+            SprinklerKind::Basic => "Sprinkler",
+            SprinklerKind::Quality => "Quality Sprinkler",
+            SprinklerKind::Iridium => "Iridium Sprinkler",
+        }
+    }
+
+    pub fn icon(&self) -> &str {
+        match self {
+            SprinklerKind::Basic => "💦",
+            SprinklerKind::Quality => "⛲",
+            SprinklerKind::Iridium => "🌀",
+        }
+    }
+
+    pub fn price(&self) -> u32 {
+        match self {
+            SprinklerKind::Basic => 100,
+            SprinklerKind::Quality => 300,
+            SprinklerKind::Iridium => 800,
+        }
+    }
+
+    pub fn range_description(&self) -> &str {
+        match self {
+            SprinklerKind::Basic => "4 adjacent plots",
+            SprinklerKind::Quality => "3x3 area",
+            SprinklerKind::Iridium => "5x5 area",
+        }
+    }
+}
+
+/// A sprinkler placed on a farm plot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct Sprinkler {
+    pub kind: SprinklerKind,
+    pub plot: usize,
+}
+
+impl Sprinkler {
+    /// Plots watered by this sprinkler every morning
+    pub fn covered_plots(&self) -> Vec<usize> {
+        let radius = match self.kind {
+            SprinklerKind::Basic => return neighbors(self.plot),
+            SprinklerKind::Quality => 1,
+            SprinklerKind::Iridium => 2,
+        };
+
+        let (x, y) = plot_coords(self.plot);
+        let mut plots = Vec::new();
+
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+
+                let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy))
+                else {
+                    continue;
+                };
+                plots.extend(plot_at(x, y));
+            }
+        }
+
+        plots
+    }
+}
+
+pub fn sprinkler_catalog() -> Vec<SprinklerKind> {
+    vec![
+        SprinklerKind::Basic,
+        SprinklerKind::Quality,
+        SprinklerKind::Iridium,
+    ]
+}
+
+pub fn find_sprinkler(name: &str) -> Option<SprinklerKind> {
+    sprinkler_catalog()
+        .into_iter()
+        .find(|kind| kind.name() == name)
+}
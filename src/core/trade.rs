@@ -0,0 +1,210 @@
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use super::{Player, animal::Animal, crop::Crop};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum TradeItemType {
+    Crop,
+    Money,
+    /// A stack of produce, artisan goods or tools; `amount` is the quantity
+    Item,
+    Animal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct TradeItem {
+    pub item_type: TradeItemType,
+    pub amount: Option<u32>,
+    pub crop: Option<Crop>,
+    #[serde(default)]
+    pub item_name: Option<String>,
+    #[serde(default)]
+    pub animal: Option<Animal>,
+}
+
+impl TradeItem {
+    /// Short human readable description, e.g. "3x Egg"
+    pub fn describe(&self) -> String {
+        match self.item_type {
+            TradeItemType::Money => format!("{} coins", self.amount.unwrap_or(0)),
+            TradeItemType::Crop => self
+                .crop
+                .as_ref()
+                .map(|crop| format!("{} crop", crop.name))
+                .unwrap_or_default(),
+            TradeItemType::Item => format!(
+                "{}x {}",
+                self.amount.unwrap_or(0),
+                self.item_name.as_deref().unwrap_or_default()
+            ),
+            TradeItemType::Animal => self
+                .animal
+                .as_ref()
+                .map(|animal| format!("{} the {}", animal.name, animal.kind.name()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Check the sending player has the goods before offering them
+    pub fn check(&self, player: &Player) -> Result<()> {
+        match self.item_type {
+            TradeItemType::Money => {
+                let amount = self.amount.unwrap_or(0);
+                if amount == 0 || amount > player.money {
+                    bail!("Not enough money! You have {} coins", player.money);
+                }
+            }
+            TradeItemType::Crop => {
+                let crop = self.crop.as_ref().map(|crop| crop.id);
+                if !player.inventory.iter().any(|c| Some(c.id) == crop) {
+                    bail!("That crop isn't in your inventory");
+                }
+            }
+            TradeItemType::Item => {
+                let name = self.item_name.as_deref().unwrap_or_default();
+                let amount = self.amount.unwrap_or(0);
+                if amount == 0 || amount > player.item_count(name) {
+                    bail!("You only have {} {}", player.item_count(name), name);
+                }
+            }
+            TradeItemType::Animal => {
+                let animal = self.animal.as_ref().map(|animal| animal.id);
+                if !player.animals.iter().any(|a| Some(a.id) == animal) {
+                    bail!("That animal isn't yours");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Give the traded goods to the receiving player
+    pub fn receive(&self, player: &mut Player) -> Result<()> {
+        match self.item_type {
+            TradeItemType::Money => {
+                if let Some(amount) = self.amount {
+                    player.money = player
+                        .money
+                        .checked_add(amount)
+                        .context("That's more money than you can hold")?;
+                }
+            }
+            TradeItemType::Crop => {
+                if let Some(crop) = &self.crop {
+                    player.inventory.push(crop.clone());
+                }
+            }
+            TradeItemType::Item => {
+                if let (Some(name), Some(amount)) = (&self.item_name, self.amount) {
+                    if player.item_count(name).checked_add(amount).is_none() {
+                        bail!("That's more {} than you can store", name);
+                    }
+                    player.add_item(name, amount);
+                }
+            }
+            TradeItemType::Animal => {
+                if let Some(animal) = &self.animal {
+                    player.add_animal(animal.clone())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Take the traded goods from the sending player, failing if they don't have them
+    pub fn give(&self, player: &mut Player) -> Result<()> {
+        self.check(player)?;
+
+        match self.item_type {
+            TradeItemType::Money => {
+                if let Some(amount) = self.amount {
+                    player.money = player
+                        .money
+                        .checked_sub(amount)
+                        .context("Not enough money")?;
+                }
+            }
+            TradeItemType::Crop => {
+                if let Some(crop) = &self.crop
+                    && let Some(pos) = player.inventory.iter().position(|c| c.id == crop.id)
+                {
+                    player.inventory.remove(pos);
+                }
+            }
+            TradeItemType::Item => {
+                if let (Some(name), Some(amount)) = (&self.item_name, self.amount) {
+                    player.take_item(name, amount)?;
+                }
+            }
+            TradeItemType::Animal => {
+                if let Some(animal) = &self.animal {
+                    player.remove_animal(animal.id)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(item_type: TradeItemType, amount: u32, item_name: Option<&str>) -> TradeItem {
+        TradeItem {
+            item_type,
+            amount: Some(amount),
+            crop: None,
+            item_name: item_name.map(str::to_string),
+            animal: None,
+        }
+    }
+
+    #[test]
+    fn receiving_too_many_items_is_refused() {
+        let mut player = Player::new("Tester");
+        player.add_item("Egg", 1);
+
+        let flood = item(TradeItemType::Item, u32::MAX, Some("Egg"));
+        assert!(flood.receive(&mut player).is_err());
+        assert_eq!(player.item_count("Egg"), 1);
+    }
+
+    #[test]
+    fn receiving_too_much_money_is_refused() {
+        let mut player = Player::new("Tester");
+        let money = player.money;
+
+        let flood = item(TradeItemType::Money, u32::MAX, None);
+        assert!(flood.receive(&mut player).is_err());
+        assert_eq!(player.money, money);
+    }
+
+    #[test]
+    fn giving_checks_the_goods_first() {
+        let mut player = Player::new("Tester");
+        let money = player.money;
+
+        assert!(
+            item(TradeItemType::Money, money + 1, None)
+                .give(&mut player)
+                .is_err()
+        );
+        assert!(
+            item(TradeItemType::Item, 1, Some("Egg"))
+                .give(&mut player)
+                .is_err()
+        );
+        assert_eq!(player.money, money);
+
+        item(TradeItemType::Money, money, None)
+            .give(&mut player)
+            .unwrap();
+        assert_eq!(player.money, 0);
+    }
+}
@@ -1,19 +1,89 @@
-use crate::core::{crop::Crop, season::Season};
+use std::collections::BTreeMap;
+
+use crate::core::{
+    animal::{Animal, AnimalKind, BuildingKind},
+    crafting::CraftingJob,
+    crop::Crop,
+    festival::Festival,
+    machine::Machine,
+    season::{Calendar, Season},
+    soil::Plot,
+    sprinkler::Sprinkler,
+};
 use serde::{Deserialize, Serialize};
 
 /// Commands that can be executed on the game engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub enum GameCommand {
     Sleep,
-    PlantCrop { crop_index: usize },
+    PlantCrop {
+        crop_index: usize,
+        /// Plot to plant in; the first free plot when omitted
+        #[serde(default)]
+        plot: Option<usize>,
+    },
     WaterCrops,
     HarvestCrops,
     AdvanceDay,
-    BuySeed { seed_name: String },
+    BuySeed {
+        seed_name: String,
+    },
+    SetTimeMode {
+        mode: TimeMode,
+        minutes_per_day: Option<i64>,
+    },
+    EnterCropContest {
+        field_index: usize,
+    },
+    BuyItem {
+        item_name: String,
+    },
+    ApplyFertilizer {
+        plot: usize,
+        item_name: String,
+    },
+    PlaceSprinkler {
+        plot: usize,
+        item_name: String,
+    },
+    RemoveSprinkler {
+        plot: usize,
+    },
+    /// Harvest ready crops into the item inventory instead of selling them
+    GatherCrops,
+    SellItem {
+        item_name: String,
+        quantity: u32,
+    },
+    Craft {
+        recipe_id: String,
+    },
+    PlaceMachine {
+        plot: usize,
+        item_name: String,
+    },
+    RemoveMachine {
+        plot: usize,
+    },
+    LoadMachine {
+        plot: usize,
+        item_name: String,
+    },
+    CollectMachineProducts,
+    Build {
+        building: BuildingKind,
+    },
+    BuyAnimal {
+        kind: AnimalKind,
+        name: String,
+    },
+    FeedAnimals,
 }
 
 /// Result of executing a command
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub enum GameResult {
     Success(GameEvent),
     Error(String),
@@ -21,18 +91,26 @@ pub enum GameResult {
 
 /// Events that occur as a result of commands or game progression
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub enum GameEvent {
     DayAdvanced {
         new_day: u32,
         season_change: Option<SeasonChangeEvent>,
+        overnight: Vec<GameEvent>,
+        season_warning: Option<SeasonWarning>,
+        festival: Option<Festival>,
     },
     Slept {
         old_day: u32,
         new_day: u32,
         season_change: Option<SeasonChangeEvent>,
+        overnight: Vec<GameEvent>,
+        season_warning: Option<SeasonWarning>,
+        festival: Option<Festival>,
     },
     CropPlanted {
         crop_name: String,
+        plot: usize,
         remaining_energy: u8,
     },
     CropsWatered {
@@ -41,6 +119,8 @@ pub enum GameEvent {
     CropsHarvested {
         earnings: u32,
         total_money: u32,
+        extra_yields: u32,
+        regrowing: Vec<String>,
     },
     EnergyRestored,
     SeedPurchased {
@@ -48,9 +128,138 @@ pub enum GameEvent {
         cost: u32,
         remaining_money: u32,
     },
+    /// The command in `after` earned enough XP for a new farming level
+    LeveledUp {
+        after: Box<GameEvent>,
+        level: u8,
+        perks: Vec<String>,
+    },
+    PassedOut {
+        after: Box<GameEvent>,
+        money_lost: u32,
+        new_day: u32,
+        remaining_energy: u8,
+        season_change: Option<SeasonChangeEvent>,
+        overnight: Vec<GameEvent>,
+        season_warning: Option<SeasonWarning>,
+        festival: Option<Festival>,
+    },
+    GiantCropFormed {
+        crop_name: String,
+        plot: usize,
+    },
+    CropMutated {
+        parent_a: String,
+        parent_b: String,
+        seed_name: String,
+    },
+    CropContestJudged {
+        festival_name: String,
+        crop_name: String,
+        score: u32,
+        placement: u8,
+        prize: u32,
+        total_money: u32,
+    },
+    TimeModeChanged {
+        mode: TimeMode,
+        minutes_per_day: i64,
+    },
+    OfflineCatchUp {
+        days_passed: u32,
+        days_simulated: u32,
+        season_changes: Vec<SeasonChangeEvent>,
+        crops_ready: Vec<String>,
+    },
+    ItemPurchased {
+        item_name: String,
+        cost: u32,
+        remaining_money: u32,
+    },
+    FertilizerApplied {
+        item_name: String,
+        plot: usize,
+        fertility: u8,
+    },
+    SprinklerPlaced {
+        item_name: String,
+        plot: usize,
+    },
+    SprinklerRemoved {
+        item_name: String,
+        plot: usize,
+    },
+    SprinklersWatered {
+        crops: Vec<String>,
+    },
+    CropsGathered {
+        produce: BTreeMap<String, u32>,
+        /// Coins earned for crops that couldn't be stored
+        earnings: u32,
+        /// Coins paid for the quality of the stored produce
+        #[serde(default)]
+        quality_bonus: u32,
+        total_money: u32,
+        regrowing: Vec<String>,
+    },
+    ItemSold {
+        item_name: String,
+        quantity: u32,
+        earnings: u32,
+        total_money: u32,
+    },
+    CraftingStarted {
+        recipe_name: String,
+        ready_day: u32,
+    },
+    CraftingFinished {
+        items: Vec<String>,
+    },
+    MachinePlaced {
+        item_name: String,
+        plot: usize,
+    },
+    MachineRemoved {
+        item_name: String,
+        plot: usize,
+    },
+    MachineLoaded {
+        machine_name: String,
+        input: String,
+        output: String,
+        ready_day: u32,
+    },
+    MachinesFinished {
+        products: Vec<String>,
+    },
+    MachineProductsCollected {
+        products: Vec<String>,
+    },
+    BuildingBuilt {
+        building: BuildingKind,
+        cost: u32,
+        remaining_money: u32,
+    },
+    AnimalPurchased {
+        name: String,
+        kind: AnimalKind,
+        cost: u32,
+        remaining_money: u32,
+    },
+    AnimalsFed {
+        fed: u32,
+        feed_used: BTreeMap<String, u32>,
+        hungry: Vec<String>,
+    },
+    /// Morning chores: products gathered from fed animals
+    AnimalsTended {
+        products: BTreeMap<String, u32>,
+        hungry: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub struct SeasonChangeEvent {
     pub old_season: Season,
     pub new_season: Season,
@@ -58,8 +267,18 @@ pub struct SeasonChangeEvent {
     pub crops_died: Vec<String>,
 }
 
+/// Warning about crops that will wither before they mature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub struct SeasonWarning {
+    pub next_season: Season,
+    pub days_until_season_end: u32,
+    pub crops_at_risk: Vec<String>,
+}
+
 /// Read-only game state information
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
 pub struct GameInfo {
     pub day: u32,
     pub player_name: String,
@@ -69,20 +288,76 @@ pub struct GameInfo {
     pub current_season: Season,
     pub year: u32,
     pub day_in_season: u32,
+    pub days_until_season_end: u32,
+    pub next_season: Season,
+    pub crops_at_risk: Vec<String>,
+    pub calendar: Calendar,
     pub inventory: Vec<Crop>,
     pub fields: Vec<Crop>,
+    pub plots: Vec<Plot>,
+    pub items: BTreeMap<String, u32>,
+    pub sprinklers: Vec<Sprinkler>,
+    pub crafting: Vec<CraftingJob>,
+    pub machines: Vec<Machine>,
+    pub buildings: Vec<BuildingKind>,
+    pub animals: Vec<Animal>,
+    pub farming_level: u8,
+    pub farming_xp: u32,
+    /// Total XP needed for the next level; `None` at the max level
+    pub next_level_xp: Option<u32>,
+    /// Energy saved on each planting and watering task
+    pub energy_discount: u8,
+    pub time_mode: TimeMode,
+    pub minutes_per_day: i64,
+    pub hour: u32,
+    pub minute: u32,
+    pub shop_open: bool,
+    pub festival: Option<Festival>,
+}
+
+/// How days advance in the game
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(tsify::Tsify))]
+pub enum TimeMode {
+    /// Days advance automatically every `auto_day_change_minutes`, or on sleep
+    #[default]
+    RealTime,
+    /// Days only advance when the player sleeps
+    TurnBased,
+    /// Days never advance
+    Paused,
+}
+
+impl TimeMode {
+    pub fn name(&self) -> &str {
+        match self {
+            TimeMode::RealTime => "Real-time",
+            TimeMode::TurnBased => "Turn-based",
+            TimeMode::Paused => "Paused",
+        }
+    }
 }
 
 /// Configuration for automatic day progression
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeConfig {
+    #[serde(default)]
+    pub mode: TimeMode,
     pub auto_day_change_minutes: i64,
     pub last_day_change: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+impl TimeConfig {
+    /// Whether days should advance automatically with real time
+    pub fn is_real_time(&self) -> bool {
+        self.mode == TimeMode::RealTime && self.auto_day_change_minutes > 0
+    }
+}
+
 impl Default for TimeConfig {
     fn default() -> Self {
         Self {
+            mode: TimeMode::default(),
             auto_day_change_minutes: 2,
             last_day_change: Some(chrono::Utc::now()),
         }
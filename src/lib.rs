@@ -9,6 +9,10 @@ pub mod cli;
 #[cfg(feature = "network")]
 pub mod network;
 
+// Local HTTP/WebSocket API - requires native features
+#[cfg(feature = "server")]
+pub mod server;
+
 // WASM bindings - requires wasm feature
 #[cfg(feature = "wasm")]
 pub mod wasm;
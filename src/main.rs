@@ -1,13 +1,17 @@
-use std::process::ExitCode;
+use std::{path::PathBuf, process::ExitCode};
 
-use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use anyhow::{Context, Result, bail};
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[cfg(feature = "cli")]
-use p2p_harvest_game::cli::{CliApp, GamePersistence};
+use p2p_harvest_game::cli::{CliApp, GamePersistence, script};
+use p2p_harvest_game::core::{Calendar, Season, season::DEFAULT_DAYS_PER_SEASON, types::TimeMode};
 
 #[derive(Parser)]
 struct Args {
+    /// Save slot to play, so several farms can be kept side by side
+    #[clap(long, global = true, default_value = "default")]
+    slot: String,
     #[clap(subcommand)]
     command: Command,
 }
@@ -15,24 +19,257 @@ struct Args {
 #[derive(Subcommand)]
 enum Command {
     /// Start a game
-    Start,
-    /// Reset the game state
+    Start {
+        /// How days advance
+        #[clap(long, value_enum)]
+        time_mode: Option<TimeModeArg>,
+        /// Real-time minutes per in-game day
+        #[clap(long)]
+        minutes_per_day: Option<i64>,
+        /// Days in each season (new games only)
+        #[clap(long, default_value_t = DEFAULT_DAYS_PER_SEASON)]
+        days_per_season: u32,
+        /// Seasons making up a year, in order (new games only)
+        #[clap(long, value_enum, value_delimiter = ',', default_values_t = [SeasonArg::Spring, SeasonArg::Summer, SeasonArg::Autumn, SeasonArg::Winter])]
+        seasons: Vec<SeasonArg>,
+        /// Season the game starts in (new games only)
+        #[clap(long, value_enum, default_value_t = SeasonArg::Spring)]
+        starting_season: SeasonArg,
+        /// Use the full-screen terminal UI
+        #[clap(long)]
+        tui: bool,
+    },
+    /// Run a file of commands against the saved game, one command per line
+    RunScript {
+        /// Path of the script to run
+        path: PathBuf,
+    },
+    /// Execute commands read from stdin against the saved game, one per line
+    Exec {
+        /// Read `GameCommand` JSON lines and write `GameResult` JSON lines
+        #[clap(long)]
+        json: bool,
+    },
+    /// Serve the saved game over a local HTTP and WebSocket API
+    #[cfg(feature = "server")]
+    Serve {
+        /// Port to listen on (localhost only)
+        #[clap(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Carry trades between a browser player and the P2P network
+    #[cfg(all(feature = "server", feature = "network"))]
+    Relay {
+        /// Port the browser connects to (localhost only)
+        #[clap(long, default_value_t = 8081)]
+        port: u16,
+    },
+    /// Reset the game state, keeping its backups
     Reset,
+    /// List the save slots and their backups
+    Slots,
+    /// Restore a backup of the saved game, 1 being the newest
+    Restore {
+        #[clap(default_value_t = 1)]
+        backup: usize,
+    },
+    /// Export the saved game as a portable bundle
+    Export {
+        /// Path of the bundle file to write
+        path: PathBuf,
+    },
+    /// Import a portable save bundle, replacing the current save
+    Import {
+        /// Path of the bundle file to read
+        path: PathBuf,
+        /// Overwrite an existing save
+        #[clap(long)]
+        force: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TimeModeArg {
+    RealTime,
+    TurnBased,
+    Paused,
+}
+
+impl From<TimeModeArg> for TimeMode {
+    fn from(arg: TimeModeArg) -> Self {
+        match arg {
+            TimeModeArg::RealTime => TimeMode::RealTime,
+            TimeModeArg::TurnBased => TimeMode::TurnBased,
+            TimeModeArg::Paused => TimeMode::Paused,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SeasonArg {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl From<SeasonArg> for Season {
+    fn from(arg: SeasonArg) -> Self {
+        match arg {
+            SeasonArg::Spring => Season::Spring,
+            SeasonArg::Summer => Season::Summer,
+            SeasonArg::Autumn => Season::Autumn,
+            SeasonArg::Winter => Season::Winter,
+        }
+    }
 }
 
 fn main() -> Result<ExitCode> {
     let args = Args::parse();
+    GamePersistence::select_slot(&args.slot)?;
 
     match args.command {
-        Command::Start => {
+        Command::Start {
+            time_mode,
+            minutes_per_day,
+            days_per_season,
+            seasons,
+            starting_season,
+            tui,
+        } => {
+            let calendar = Calendar::new(
+                days_per_season,
+                seasons.into_iter().map(Season::from).collect(),
+                starting_season.into(),
+            )?;
+
             println!("🌱 Welcome to the P2P Harvest Game! 🌱");
-            let mut app = CliApp::load_or_create()?;
-            app.run().context("Failed to run game")?;
+            let mut app = CliApp::load_or_create(calendar)?;
+            app.apply_time_settings(time_mode.map(TimeMode::from), minutes_per_day)?;
+            if tui {
+                app.run_tui().context("Failed to run game")?;
+            } else {
+                app.run().context("Failed to run game")?;
+            }
+        }
+        Command::RunScript { path } => {
+            let script = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let mut stdout = std::io::stdout().lock();
+            let mut engine = GamePersistence::resume(&mut stdout)?
+                .context("No saved game found. Start a game first.")?;
+
+            let outcome = script::run_script(&mut engine, &script, &mut stdout);
+            // Keep the commands that ran even if the output broke off
+            GamePersistence::save(&engine)?;
+            let failures = outcome?;
+
+            if failures > 0 {
+                eprintln!("😖 {} command(s) failed", failures);
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Exec { json } => {
+            let stdin = std::io::stdin().lock();
+            let mut stdout = std::io::stdout().lock();
+            // JSON output holds one result per command, so missed days go to stderr
+            let engine = if json {
+                GamePersistence::resume(&mut std::io::stderr().lock())?
+            } else {
+                GamePersistence::resume(&mut stdout)?
+            };
+            let mut engine = engine.context("No saved game found. Start a game first.")?;
+
+            let outcome = if json {
+                script::execute_json_lines(&mut engine, stdin, &mut stdout)
+            } else {
+                let script = std::io::read_to_string(stdin).context("Failed to read stdin")?;
+                script::run_script(&mut engine, &script, &mut stdout)
+            };
+            // Keep the commands that ran even if reading or writing broke off
+            GamePersistence::save(&engine)?;
+            let failures = outcome?;
+
+            if failures > 0 {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        #[cfg(feature = "server")]
+        Command::Serve { port } => {
+            let engine = GamePersistence::resume(&mut std::io::stdout().lock())?
+                .context("No saved game found. Start a game first.")?;
+            p2p_harvest_game::server::serve(engine, port)?;
+        }
+        #[cfg(all(feature = "server", feature = "network"))]
+        Command::Relay { port } => {
+            p2p_harvest_game::server::relay(port)?;
         }
         Command::Reset => {
             GamePersistence::reset()?;
             println!("Game state has been reset.");
         }
+        Command::Slots => {
+            let slots = GamePersistence::slots();
+            for slot in slots.slots()? {
+                let marker = if slot == GamePersistence::slot() {
+                    "▶"
+                } else {
+                    " "
+                };
+                match slots.load(&slot) {
+                    Ok(Some(engine)) => println!(
+                        "{} 💾 {}: {}'s farm, day {}",
+                        marker,
+                        slot,
+                        engine.get_player().name,
+                        engine.get_day()
+                    ),
+                    Ok(None) => {}
+                    Err(err) => println!("{} ❌ {}: {:#}", marker, slot, err),
+                }
+
+                let backups = slots.backups(&slot)?;
+                if !backups.is_empty() {
+                    let days: Vec<String> = backups
+                        .iter()
+                        .enumerate()
+                        .map(|(index, day)| match day {
+                            Ok(day) => format!("day {}", day),
+                            Err(_) => format!("#{} unreadable", index + 1),
+                        })
+                        .collect();
+                    println!("     🗄️  Backups: {}", days.join(", "));
+                }
+                for err in backups.iter().filter_map(|day| day.as_ref().err()) {
+                    println!("     ❌ {:#}", err);
+                }
+            }
+        }
+        Command::Restore { backup } => {
+            let engine =
+                GamePersistence::slots().restore_backup(GamePersistence::slot(), backup)?;
+            println!(
+                "🗄️  Restored {}'s farm (day {})",
+                engine.get_player().name,
+                engine.get_day()
+            );
+        }
+        Command::Export { path } => {
+            GamePersistence::export_to(&path)?;
+            println!("📦 Game exported to {}", path.display());
+        }
+        Command::Import { path, force } => {
+            if GamePersistence::save_exists() && !force {
+                bail!("A saved game already exists. Use --force to overwrite it.");
+            }
+
+            let engine = GamePersistence::import_from(&path)?;
+            println!(
+                "📂 Imported {}'s farm (day {})",
+                engine.get_player().name,
+                engine.get_day()
+            );
+        }
     }
 
     Ok(ExitCode::SUCCESS)
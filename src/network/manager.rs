@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 use iroh::EndpointId;
 use std::sync::Arc;
-use tokio::{runtime::Runtime, sync::Mutex};
+use tokio::{
+    runtime::Runtime,
+    sync::{Mutex, mpsc},
+};
 use tokio_stream::StreamExt;
 
 use crate::core::GameEngine;
@@ -22,10 +25,11 @@ impl TradeManager {
         })
     }
 
-    pub fn initialize(&mut self, game_engine: GameEngine) -> Result<()> {
+    /// Start a trade node that trades from the given farm
+    pub fn initialize(&mut self, game_engine: Arc<Mutex<GameEngine>>) -> Result<()> {
         let trade_node = self
             .runtime
-            .block_on(async { TradeNode::spawn(game_engine).await })?;
+            .block_on(async { TradeNode::spawn_shared(game_engine).await })?;
 
         self.trade_node = Some(trade_node);
         Ok(())
@@ -38,7 +42,9 @@ impl TradeManager {
     }
 
     pub fn get_game_engine(&self) -> Option<Arc<Mutex<GameEngine>>> {
-        self.trade_node.as_ref().map(|node| node.get_game_engine())
+        self.trade_node
+            .as_ref()
+            .and_then(|node| node.get_game_engine())
     }
 
     pub fn send_trade(&self, remote_endpoint_id: EndpointId, trade_item: TradeItem) -> Result<()> {
@@ -51,58 +57,34 @@ impl TradeManager {
             let mut stream = trade_node.trade(remote_endpoint_id, trade_item);
 
             while let Some(event) = stream.next().await {
-                match event {
-                    TradeEvent::Connected => {
-                        println!("✅ Connected to peer");
-                    }
-                    TradeEvent::TradeProposed {
-                        item_type,
-                        amount,
-                        crop,
-                    } => {
-                        use super::trade_protocol::TradeItemType;
-                        match item_type {
-                            TradeItemType::Money => {
-                                println!("💰 Sending {} coins...", amount.unwrap_or(0));
-                            }
-                            TradeItemType::Crop => {
-                                if let Some(crop) = crop {
-                                    println!("🌾 Sending {} crop...", crop.name);
-                                }
-                            }
-                        }
-                    }
-                    TradeEvent::TradeAccepted {
-                        item_type,
-                        amount,
-                        crop,
-                    } => {
-                        use super::trade_protocol::TradeItemType;
-                        match item_type {
-                            TradeItemType::Money => {
-                                println!("✅ Trade accepted! Sent {} coins", amount.unwrap_or(0));
-                            }
-                            TradeItemType::Crop => {
-                                if let Some(crop) = crop {
-                                    println!("✅ Trade accepted! Sent {}", crop.name);
-                                }
-                            }
-                        }
-                    }
-                    TradeEvent::Closed { error } => {
-                        if let Some(err) = error {
-                            println!("❌ Trade failed: {}", err);
-                        } else {
-                            println!("✅ Trade completed successfully!");
-                        }
-                        break;
-                    }
+                println!("{}", event.summary());
+                if let TradeEvent::Closed { .. } = event {
+                    break;
                 }
             }
             Ok(())
         })
     }
 
+    /// Forward a summary of every incoming trade to `inbox` until it is closed
+    pub fn watch_trades(&self, inbox: mpsc::UnboundedSender<String>) -> Result<()> {
+        let trade_node = self
+            .trade_node
+            .as_ref()
+            .context("Trade node not initialized")?;
+        let mut stream = trade_node.accept();
+
+        self.runtime.spawn(async move {
+            while let Some(event) = stream.next().await {
+                if inbox.send(event.summary()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
     pub fn listen_for_trades(&self, timeout_secs: u64) -> Result<()> {
         let trade_node = self
             .trade_node
@@ -128,22 +110,13 @@ impl TradeManager {
                             }
                             AcceptTradeEvent::TradeReceived {
                                 endpoint_id,
-                                item_type,
-                                amount,
-                                crop,
+                                trade_item,
                             } => {
-                                use super::trade_protocol::TradeItemType;
                                 println!("📦 Trade received from {}", endpoint_id);
-                                match item_type {
-                                    TradeItemType::Money => {
-                                        println!("💰 Received {} coins!", amount.unwrap_or(0));
-                                    }
-                                    TradeItemType::Crop => {
-                                        if let Some(crop) = crop {
-                                            println!("🌾 Received {} crop!", crop.name);
-                                        }
-                                    }
-                                }
+                                println!("🎁 Received {}!", trade_item.describe());
+                            }
+                            AcceptTradeEvent::TradeRejected { endpoint_id, reason } => {
+                                println!("🚫 Refused trade from {}: {}", endpoint_id, reason);
                             }
                             AcceptTradeEvent::TradeCompleted { endpoint_id } => {
                                 println!("✅ Trade with {} completed!", endpoint_id);
@@ -1,48 +1,73 @@
-use std::sync::Arc;
+use std::{future::Future, pin::Pin, sync::Arc};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use async_channel::Sender;
 use iroh::{
     Endpoint, EndpointId,
-    endpoint::Connection,
+    endpoint::{Connection, RecvStream},
     protocol::{AcceptError, ProtocolHandler, Router},
 };
 use n0_future::{Stream, boxed::BoxStream, task};
-use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, broadcast};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 
-use crate::core::{GameEngine, crop::Crop};
+use crate::core::GameEngine;
+pub use crate::core::trade::{TradeItem, TradeItemType};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum TradeItemType {
-    Crop,
-    Money,
+pub type WalletFuture<'a> = Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+
+/// Where a node's traded goods come from and go to
+pub trait TradeWallet: std::fmt::Debug + Send + Sync {
+    /// Take in goods sent by a peer, or refuse them with an error
+    fn receive<'a>(&'a self, trade_item: &'a TradeItem) -> WalletFuture<'a>;
+    /// Take out goods about to be offered to a peer, holding them until it answers
+    fn give<'a>(&'a self, trade_item: &'a TradeItem) -> WalletFuture<'a>;
+    /// Put back goods the peer didn't take
+    fn refund<'a>(&'a self, trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        self.receive(trade_item)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TradeItem {
-    pub item_type: TradeItemType,
-    pub amount: Option<u32>,
-    pub crop: Option<Crop>,
+/// A node trading from its own farm
+impl TradeWallet for Mutex<GameEngine> {
+    fn receive<'a>(&'a self, trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        Box::pin(async move { trade_item.receive(self.lock().await.get_player_mut()) })
+    }
+
+    fn give<'a>(&'a self, trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        Box::pin(async move { trade_item.give(self.lock().await.get_player_mut()) })
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum TradeEvent {
     Connected,
-    TradeProposed {
-        item_type: TradeItemType,
-        amount: Option<u32>,
-        crop: Option<Crop>,
-    },
-    TradeAccepted {
-        item_type: TradeItemType,
-        amount: Option<u32>,
-        crop: Option<Crop>,
-    },
-    Closed {
-        error: Option<String>,
-    },
+    TradeProposed { trade_item: TradeItem },
+    TradeAccepted { trade_item: TradeItem },
+    TradeRejected { reason: String },
+    Closed { error: Option<String> },
+}
+
+impl TradeEvent {
+    /// One-line progress report for the sending player
+    pub fn summary(&self) -> String {
+        match self {
+            TradeEvent::Connected => "✅ Connected to peer".to_string(),
+            TradeEvent::TradeProposed { trade_item } => {
+                format!("📦 Sending {}...", trade_item.describe())
+            }
+            TradeEvent::TradeAccepted { trade_item } => {
+                format!("✅ Trade accepted! Sent {}", trade_item.describe())
+            }
+            TradeEvent::TradeRejected { reason } => {
+                format!("🚫 Trade rejected by peer: {}", reason)
+            }
+            TradeEvent::Closed { error } => match error {
+                Some(err) => format!("❌ Trade failed: {}", err),
+                None => "✅ Trade completed successfully!".to_string(),
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -52,9 +77,11 @@ pub enum AcceptTradeEvent {
     },
     TradeReceived {
         endpoint_id: EndpointId,
-        item_type: TradeItemType,
-        amount: Option<u32>,
-        crop: Option<Crop>,
+        trade_item: Box<TradeItem>,
+    },
+    TradeRejected {
+        endpoint_id: EndpointId,
+        reason: String,
     },
     TradeCompleted {
         endpoint_id: EndpointId,
@@ -65,9 +92,47 @@ pub enum AcceptTradeEvent {
     },
 }
 
+impl AcceptTradeEvent {
+    /// One-line description for trade inboxes
+    pub fn summary(&self) -> String {
+        match self {
+            AcceptTradeEvent::Connected { endpoint_id } => {
+                format!("🔗 Peer connected: {}", endpoint_id.fmt_short())
+            }
+            AcceptTradeEvent::TradeReceived {
+                endpoint_id,
+                trade_item,
+            } => format!(
+                "🎁 Received {} from {}",
+                trade_item.describe(),
+                endpoint_id.fmt_short()
+            ),
+            AcceptTradeEvent::TradeRejected {
+                endpoint_id,
+                reason,
+            } => format!(
+                "🚫 Refused trade from {}: {}",
+                endpoint_id.fmt_short(),
+                reason
+            ),
+            AcceptTradeEvent::TradeCompleted { endpoint_id } => {
+                format!("✅ Trade with {} completed", endpoint_id.fmt_short())
+            }
+            AcceptTradeEvent::Closed { endpoint_id, error } => match error {
+                Some(err) => format!(
+                    "❌ Connection with {} closed: {}",
+                    endpoint_id.fmt_short(),
+                    err
+                ),
+                None => format!("👋 Connection with {} closed", endpoint_id.fmt_short()),
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Trade {
-    game_engine: Arc<Mutex<GameEngine>>,
+    wallet: Arc<dyn TradeWallet>,
     event_sender: broadcast::Sender<AcceptTradeEvent>,
 }
 
@@ -76,11 +141,11 @@ impl Trade {
 
     pub fn new(
         event_sender: broadcast::Sender<AcceptTradeEvent>,
-        game_engine: Arc<Mutex<GameEngine>>,
+        wallet: Arc<dyn TradeWallet>,
     ) -> Self {
         Self {
             event_sender,
-            game_engine,
+            wallet,
         }
     }
 
@@ -120,41 +185,42 @@ impl Trade {
         self.event_sender
             .send(AcceptTradeEvent::TradeReceived {
                 endpoint_id,
-                item_type: trade_item.item_type.clone(),
-                amount: trade_item.amount,
-                crop: trade_item.crop.clone(),
+                trade_item: Box::new(trade_item.clone()),
             })
             .ok();
 
-        let acceptance = serde_json::json!({
-            "status": "trade_accepted",
-            "item_type": trade_item.item_type,
-            "amount": trade_item.amount,
-            "crop": trade_item.crop,
-        });
-
-        let acceptance_bytes =
-            serde_json::to_vec(&acceptance).map_err(|err| AcceptError::from_err(err))?;
-        tokio::io::copy(&mut acceptance_bytes.as_slice(), &mut send).await?;
+        // Refuse goods the player can't take
+        let outcome = self.wallet.receive(&trade_item).await;
+
+        let response = match &outcome {
+            Ok(()) => serde_json::json!({
+                "status": "trade_accepted",
+                "item_type": trade_item.item_type,
+                "amount": trade_item.amount,
+                "crop": trade_item.crop,
+                "item_name": trade_item.item_name,
+                "animal": trade_item.animal,
+            }),
+            Err(err) => serde_json::json!({
+                "status": "trade_rejected",
+                "reason": err.to_string(),
+            }),
+        };
+
+        let response_bytes =
+            serde_json::to_vec(&response).map_err(|err| AcceptError::from_err(err))?;
+        tokio::io::copy(&mut response_bytes.as_slice(), &mut send).await?;
         send.finish()?;
 
-        // Update the shared game engine
-        {
-            let mut engine = self.game_engine.lock().await;
-            let player = engine.get_player_mut();
-
-            match trade_item.item_type {
-                TradeItemType::Money => {
-                    if let Some(amount) = trade_item.amount {
-                        player.money += amount;
-                    }
-                }
-                TradeItemType::Crop => {
-                    if let Some(crop) = trade_item.crop {
-                        player.inventory.push(crop);
-                    }
-                }
-            }
+        if let Err(err) = outcome {
+            self.event_sender
+                .send(AcceptTradeEvent::TradeRejected {
+                    endpoint_id,
+                    reason: err.to_string(),
+                })
+                .ok();
+            connection.closed().await;
+            return Ok(());
         }
 
         self.event_sender
@@ -177,19 +243,36 @@ impl ProtocolHandler for Trade {
 pub struct TradeNode {
     router: Router,
     accept_events: broadcast::Sender<AcceptTradeEvent>,
-    game_engine: Arc<Mutex<GameEngine>>,
+    wallet: Arc<dyn TradeWallet>,
+    game_engine: Option<Arc<Mutex<GameEngine>>>,
 }
 
 impl TradeNode {
     pub async fn spawn(game_engine: GameEngine) -> Result<Self> {
+        Self::spawn_shared(Arc::new(Mutex::new(game_engine))).await
+    }
+
+    /// Spawn a node trading from a farm that is also used elsewhere
+    pub async fn spawn_shared(game_engine: Arc<Mutex<GameEngine>>) -> Result<Self> {
+        Self::spawn_with(game_engine.clone(), Some(game_engine)).await
+    }
+
+    /// Spawn a node with no farm of its own, trading on behalf of a remote player
+    pub async fn spawn_relay(wallet: Arc<dyn TradeWallet>) -> Result<Self> {
+        Self::spawn_with(wallet, None).await
+    }
+
+    async fn spawn_with(
+        wallet: Arc<dyn TradeWallet>,
+        game_engine: Option<Arc<Mutex<GameEngine>>>,
+    ) -> Result<Self> {
         let endpoint_builder = iroh::Endpoint::builder()
             .alpns(vec![Trade::ALPN.to_vec()])
             .bind()
             .await?;
 
-        let game_engine_arc = Arc::new(Mutex::new(game_engine));
         let (event_sender, _) = broadcast::channel(128);
-        let trade = Trade::new(event_sender.clone(), game_engine_arc.clone());
+        let trade = Trade::new(event_sender.clone(), wallet.clone());
         let router = Router::builder(endpoint_builder)
             .accept(Trade::ALPN, trade)
             .spawn();
@@ -197,7 +280,8 @@ impl TradeNode {
         Ok(Self {
             router,
             accept_events: event_sender,
-            game_engine: game_engine_arc,
+            wallet,
+            game_engine,
         })
     }
 
@@ -205,7 +289,8 @@ impl TradeNode {
         self.router.endpoint()
     }
 
-    pub fn get_game_engine(&self) -> Arc<Mutex<GameEngine>> {
+    /// The node's farm, or `None` for a relay
+    pub fn get_game_engine(&self) -> Option<Arc<Mutex<GameEngine>>> {
         self.game_engine.clone()
     }
 
@@ -214,55 +299,44 @@ impl TradeNode {
         endpoint_id: EndpointId,
         event_sender: Sender<TradeEvent>,
         trade_item: TradeItem,
-        game_engine: Arc<Mutex<GameEngine>>,
+        wallet: Arc<dyn TradeWallet>,
     ) -> Result<()> {
-        let connection = endpoint.connect(endpoint_id, Trade::ALPN).await?;
-        event_sender.send(TradeEvent::Connected).await?;
-
-        let (mut send_stream, mut recv_stream) = connection.open_bi().await?;
-        let payload = serde_json::to_vec(&trade_item)?;
-
-        event_sender
-            .send(TradeEvent::TradeProposed {
-                item_type: trade_item.item_type.clone(),
-                amount: trade_item.amount,
-                crop: trade_item.crop.clone(),
-            })
-            .await?;
-        tokio::io::copy(&mut payload.as_slice(), &mut send_stream).await?;
-        send_stream.finish()?;
+        // Hold the goods while the trade is under way, so they can't be spent or sent twice
+        wallet.give(&trade_item).await?;
+
+        let offer = Self::offer_trade(endpoint, endpoint_id, &event_sender, &trade_item).await;
+        let (connection, mut recv_stream) = match offer {
+            Ok(offer) => offer,
+            Err(err) => {
+                wallet.refund(&trade_item).await?;
+                return Err(err);
+            }
+        };
 
+        // The peer takes the goods before answering, so they stay sent if the answer is lost
         let mut buffer = Vec::new();
         tokio::io::copy(&mut recv_stream, &mut buffer).await?;
 
         let res: serde_json::Value = serde_json::from_slice(&buffer)?;
 
         if res["status"] == "trade_accepted" {
-            let mut engine = game_engine.lock().await;
-            let player = engine.get_player_mut();
-
-            match trade_item.item_type {
-                TradeItemType::Money => {
-                    if let Some(amount) = trade_item.amount {
-                        player.money -= amount;
-                    }
-                }
-                TradeItemType::Crop => {
-                    if let Some(crop) = &trade_item.crop {
-                        if let Some(pos) = player.inventory.iter().position(|c| c.id == crop.id) {
-                            player.inventory.remove(pos);
-                        }
-                    }
-                }
-            }
+            event_sender
+                .send(TradeEvent::TradeAccepted { trade_item })
+                .await?;
+        } else if res["status"] == "trade_rejected" {
+            wallet.refund(&trade_item).await?;
 
+            let reason = res["reason"]
+                .as_str()
+                .unwrap_or("unknown reason")
+                .to_string();
             event_sender
-                .send(TradeEvent::TradeAccepted {
-                    item_type: trade_item.item_type,
-                    amount: trade_item.amount,
-                    crop: trade_item.crop,
+                .send(TradeEvent::TradeRejected {
+                    reason: reason.clone(),
                 })
                 .await?;
+            connection.close(1u8.into(), b"rejected");
+            bail!("Trade rejected: {}", reason);
         }
 
         connection.close(1u8.into(), b"done");
@@ -270,14 +344,47 @@ impl TradeNode {
         Ok(())
     }
 
+    /// Connect to the peer and send it the goods, before it has a chance to take them
+    async fn offer_trade(
+        endpoint: &Endpoint,
+        endpoint_id: EndpointId,
+        event_sender: &Sender<TradeEvent>,
+        trade_item: &TradeItem,
+    ) -> Result<(Connection, RecvStream)> {
+        let connection = endpoint.connect(endpoint_id, Trade::ALPN).await?;
+        event_sender.send(TradeEvent::Connected).await?;
+
+        let (mut send_stream, recv_stream) = connection.open_bi().await?;
+        let payload = serde_json::to_vec(trade_item)?;
+
+        event_sender
+            .send(TradeEvent::TradeProposed {
+                trade_item: trade_item.clone(),
+            })
+            .await?;
+        tokio::io::copy(&mut payload.as_slice(), &mut send_stream).await?;
+        send_stream.finish()?;
+
+        Ok((connection, recv_stream))
+    }
+
     pub fn trade(
         &self,
         endpoint_id: EndpointId,
         trade_item: TradeItem,
+    ) -> impl Stream<Item = TradeEvent> + Unpin + use<> {
+        self.trade_from(endpoint_id, trade_item, self.wallet.clone())
+    }
+
+    /// Send goods taken from another wallet than the node's own
+    pub fn trade_from(
+        &self,
+        endpoint_id: EndpointId,
+        trade_item: TradeItem,
+        wallet: Arc<dyn TradeWallet>,
     ) -> impl Stream<Item = TradeEvent> + Unpin + use<> {
         let (event_sender, event_receiver) = async_channel::bounded(16);
         let endpoint = self.router.endpoint().clone();
-        let game_engine = self.game_engine.clone();
 
         task::spawn(async move {
             let res = Self::initiate_trade(
@@ -285,7 +392,7 @@ impl TradeNode {
                 endpoint_id,
                 event_sender.clone(),
                 trade_item,
-                game_engine,
+                wallet,
             )
             .await;
             let error = res.as_ref().err().map(|err| err.to_string());
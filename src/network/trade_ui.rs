@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 use iroh::EndpointId;
 use std::io::{self, StdoutLock, Write};
 use std::str::FromStr;
+use tokio::sync::Mutex;
 
 use crate::core::GameEngine;
 
@@ -13,7 +14,7 @@ use super::{
 pub fn handle_trade(
     stdout: &mut StdoutLock,
     trade_manager: &TradeManager,
-    game_engine: &mut GameEngine,
+    game_engine: &Mutex<GameEngine>,
 ) -> Result<()> {
     write!(stdout, "🎁 P2P Trade System\r\n")?;
     writeln!(stdout)?;
@@ -21,7 +22,7 @@ pub fn handle_trade(
     write!(stdout, "Select mode:\r\n")?;
     write!(
         stdout,
-        "1. Send trade (transfer crops/money to another player)\r\n"
+        "1. Send trade (transfer crops/items/animals/money to another player)\r\n"
     )?;
     write!(stdout, "2. Receive trade (listen for incoming trades)\r\n")?;
     write!(stdout, "3. Cancel\r\n")?;
@@ -34,7 +35,7 @@ pub fn handle_trade(
             handle_send_trade(stdout, trade_manager, game_engine)?;
         }
         Ok(2) => {
-            handle_receive_trade(stdout, trade_manager, game_engine)?;
+            handle_receive_trade(stdout, trade_manager)?;
         }
         Ok(3) => {
             write!(stdout, "❌ Trade cancelled.\r\n")?;
@@ -50,7 +51,7 @@ pub fn handle_trade(
 fn handle_send_trade(
     stdout: &mut StdoutLock,
     trade_manager: &TradeManager,
-    game_engine: &mut GameEngine,
+    game_engine: &Mutex<GameEngine>,
 ) -> Result<()> {
     write!(stdout, "\n📤 Send Trade\r\n")?;
 
@@ -66,11 +67,13 @@ fn handle_send_trade(
     write!(stdout, "\nWhat do you want to send?\r\n")?;
     write!(stdout, "1. Money\r\n")?;
     write!(stdout, "2. Crop\r\n")?;
+    write!(stdout, "3. Item\r\n")?;
+    write!(stdout, "4. Animal\r\n")?;
 
     let mut choice = String::new();
     io::stdin().read_line(&mut choice)?;
 
-    let info = game_engine.get_info();
+    let info = game_engine.blocking_lock().get_info();
 
     let trade_item = match choice.trim().parse::<usize>() {
         Ok(1) => {
@@ -96,6 +99,8 @@ fn handle_send_trade(
                 item_type: TradeItemType::Money,
                 amount: Some(amount),
                 crop: None,
+                item_name: None,
+                animal: None,
             }
         }
         Ok(2) => {
@@ -129,6 +134,97 @@ fn handle_send_trade(
                 item_type: TradeItemType::Crop,
                 amount: None,
                 crop: Some(crop),
+                item_name: None,
+                animal: None,
+            }
+        }
+        Ok(3) => {
+            // Send items
+            if info.items.is_empty() {
+                write!(stdout, "❌ No items in storage!\r\n")?;
+                return Ok(());
+            }
+
+            let items: Vec<(&String, &u32)> = info.items.iter().collect();
+            write!(stdout, "Your items:\r\n")?;
+            for (index, (name, count)) in items.iter().enumerate() {
+                write!(stdout, "{}. {} x{}\r\n", index + 1, name, count)?;
+            }
+
+            write!(stdout, "Select item by number:\r\n")?;
+            let mut item_input = String::new();
+            io::stdin().read_line(&mut item_input)?;
+            let item_index = item_input
+                .trim()
+                .parse::<usize>()
+                .context("Invalid item selection")?;
+
+            if item_index == 0 || item_index > items.len() {
+                write!(stdout, "❌ Invalid selection!\r\n")?;
+                return Ok(());
+            }
+
+            let (name, count) = items[item_index - 1];
+
+            write!(stdout, "How many? (you have {})\r\n", count)?;
+            let mut amount_input = String::new();
+            io::stdin().read_line(&mut amount_input)?;
+            let amount = amount_input
+                .trim()
+                .parse::<u32>()
+                .context("Invalid amount")?;
+
+            if amount == 0 || amount > *count {
+                write!(stdout, "❌ You only have {} {}!\r\n", count, name)?;
+                return Ok(());
+            }
+
+            TradeItem {
+                item_type: TradeItemType::Item,
+                amount: Some(amount),
+                crop: None,
+                item_name: Some(name.clone()),
+                animal: None,
+            }
+        }
+        Ok(4) => {
+            // Send an animal
+            if info.animals.is_empty() {
+                write!(stdout, "❌ You don't have any animals!\r\n")?;
+                return Ok(());
+            }
+
+            write!(stdout, "Your animals:\r\n")?;
+            for (index, animal) in info.animals.iter().enumerate() {
+                write!(
+                    stdout,
+                    "{}. {} {} the {}\r\n",
+                    index + 1,
+                    animal.kind.icon(),
+                    animal.name,
+                    animal.kind.name()
+                )?;
+            }
+
+            write!(stdout, "Select animal by number:\r\n")?;
+            let mut animal_input = String::new();
+            io::stdin().read_line(&mut animal_input)?;
+            let animal_index = animal_input
+                .trim()
+                .parse::<usize>()
+                .context("Invalid animal selection")?;
+
+            if animal_index == 0 || animal_index > info.animals.len() {
+                write!(stdout, "❌ Invalid selection!\r\n")?;
+                return Ok(());
+            }
+
+            TradeItem {
+                item_type: TradeItemType::Animal,
+                amount: None,
+                crop: None,
+                item_name: None,
+                animal: Some(info.animals[animal_index - 1].clone()),
             }
         }
         _ => {
@@ -142,31 +238,15 @@ fn handle_send_trade(
 
     trade_manager.send_trade(endpoint_id, trade_item)?;
 
-    // Reload game engine from the shared state in TradeNode
-    if let Some(game_engine_arc) = trade_manager.get_game_engine() {
-        let rt = tokio::runtime::Runtime::new()?;
-        *game_engine = rt.block_on(async { game_engine_arc.lock().await.clone() });
-    }
-
     Ok(())
 }
 
-fn handle_receive_trade(
-    stdout: &mut StdoutLock,
-    trade_manager: &TradeManager,
-    game_engine: &mut GameEngine,
-) -> Result<()> {
+fn handle_receive_trade(stdout: &mut StdoutLock, trade_manager: &TradeManager) -> Result<()> {
     write!(stdout, "\n📥 Receive Trade\r\n")?;
     write!(stdout, "Waiting for incoming trades...\r\n")?;
     write!(stdout, "(Will timeout after 60 seconds)\r\n\n")?;
 
     trade_manager.listen_for_trades(60)?;
 
-    // Reload game engine as it may have been updated
-    if let Some(game_engine_arc) = trade_manager.get_game_engine() {
-        let rt = tokio::runtime::Runtime::new()?;
-        *game_engine = rt.block_on(async { game_engine_arc.lock().await.clone() });
-    }
-
     Ok(())
 }
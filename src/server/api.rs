@@ -0,0 +1,240 @@
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    extract::{
+        State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+    routing::{get, post},
+};
+use serde::Serialize;
+use std::{net::SocketAddr, sync::Arc};
+use tokio::sync::{Mutex, broadcast, mpsc};
+
+use crate::cli::{GamePersistence, app::monitor_day_changes};
+use crate::core::{
+    GameEngine,
+    types::{GameCommand, GameEvent, GameInfo, GameResult},
+};
+
+#[cfg(feature = "network")]
+use crate::network::trade_protocol::{TradeEvent, TradeItem, TradeNode};
+#[cfg(feature = "network")]
+use axum::http::StatusCode;
+#[cfg(feature = "network")]
+use serde::Deserialize;
+#[cfg(feature = "network")]
+use tokio_stream::StreamExt;
+
+/// Messages pushed to every client of the event stream
+#[derive(Debug, Clone, Serialize)]
+pub enum ServerEvent {
+    /// Something happened in the game: a command from any client, a new day or a trade
+    Game(GameEvent),
+    /// Progress of an incoming trade, e.g. "📦 Received 3x Egg from ..."
+    Trade(String),
+}
+
+#[derive(Clone)]
+struct AppState {
+    engine: Arc<Mutex<GameEngine>>,
+    events: broadcast::Sender<ServerEvent>,
+    #[cfg(feature = "network")]
+    node: TradeNode,
+}
+
+#[cfg(feature = "network")]
+#[derive(Serialize)]
+struct NodeInfo {
+    endpoint_id: String,
+}
+
+#[cfg(feature = "network")]
+#[derive(Deserialize)]
+struct TradeRequest {
+    endpoint_id: String,
+    trade_item: TradeItem,
+}
+
+/// Serve the game API on localhost until Ctrl+C, saving the game on exit
+pub fn serve(game_engine: GameEngine, port: u16) -> Result<()> {
+    let runtime = tokio::runtime::Runtime::new().context("Failed to create Tokio runtime")?;
+    runtime.block_on(run(game_engine, port))
+}
+
+async fn run(game_engine: GameEngine, port: u16) -> Result<()> {
+    // The trade node owns the engine, so trades land in the same farm the API serves
+    #[cfg(feature = "network")]
+    let node = TradeNode::spawn(game_engine).await?;
+    #[cfg(feature = "network")]
+    let engine = node
+        .get_game_engine()
+        .context("Trade node has no farm")?;
+    #[cfg(not(feature = "network"))]
+    let engine = Arc::new(Mutex::new(game_engine));
+
+    let (events, _) = broadcast::channel(128);
+    let state = AppState {
+        engine: engine.clone(),
+        events: events.clone(),
+        #[cfg(feature = "network")]
+        node: node.clone(),
+    };
+
+    let (day_tx, mut day_rx) = mpsc::unbounded_channel();
+    tokio::spawn(monitor_day_changes(engine.clone(), day_tx));
+    tokio::spawn({
+        let engine = engine.clone();
+        let events = events.clone();
+        async move {
+            while let Some(event) = day_rx.recv().await {
+                save(&engine).await;
+                let _ = events.send(ServerEvent::Game(event));
+            }
+        }
+    });
+
+    #[cfg(feature = "network")]
+    {
+        println!("🔗 Trade Node initialized!");
+        println!("📋 Your Endpoint ID: {}", node.get_endpoint().id());
+
+        let mut trades = node.accept();
+        let engine = engine.clone();
+        let events = events.clone();
+        tokio::spawn(async move {
+            while let Some(event) = trades.next().await {
+                save(&engine).await;
+                let _ = events.send(ServerEvent::Trade(event.summary()));
+            }
+        });
+    }
+
+    let app = Router::new()
+        .route("/api/info", get(get_info))
+        .route("/api/command", post(execute_command))
+        .route("/api/events", get(stream_events));
+    #[cfg(feature = "network")]
+    let app = app
+        .route("/api/node", get(get_node))
+        .route("/api/trade", post(send_trade));
+    let app = app.with_state(state);
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to listen on {}", addr))?;
+    println!("🌐 Serving the game API on http://{}", addr);
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            tokio::signal::ctrl_c().await.ok();
+        })
+        .await
+        .context("Server error")?;
+
+    GamePersistence::save(&*engine.lock().await)?;
+    println!("👋 Game saved. Goodbye!");
+
+    Ok(())
+}
+
+async fn save(engine: &Mutex<GameEngine>) {
+    if let Err(err) = GamePersistence::save(&*engine.lock().await) {
+        eprintln!("❌ Failed to save game: {:#}", err);
+    }
+}
+
+async fn get_info(State(state): State<AppState>) -> Json<GameInfo> {
+    Json(state.engine.lock().await.get_info())
+}
+
+#[cfg(feature = "network")]
+async fn get_node(State(state): State<AppState>) -> Json<NodeInfo> {
+    Json(NodeInfo {
+        endpoint_id: state.node.get_endpoint().id().to_string(),
+    })
+}
+
+/// Execute a `GameCommand` and return its `GameResult`, broadcasting the event
+async fn execute_command(
+    State(state): State<AppState>,
+    Json(command): Json<GameCommand>,
+) -> Json<GameResult> {
+    let mut engine = state.engine.lock().await;
+    let result = engine.execute(command);
+
+    if let GameResult::Success(event) = &result {
+        if let Err(err) = GamePersistence::save(&engine) {
+            eprintln!("❌ Failed to save game: {:#}", err);
+        }
+        let _ = state.events.send(ServerEvent::Game(event.clone()));
+    }
+
+    Json(result)
+}
+
+/// Upgrade to a WebSocket that receives every `ServerEvent` as JSON
+async fn stream_events(State(state): State<AppState>, ws: WebSocketUpgrade) -> Response {
+    let events = state.events.subscribe();
+    ws.on_upgrade(move |socket| forward_events(socket, events))
+}
+
+async fn forward_events(mut socket: WebSocket, mut events: broadcast::Receiver<ServerEvent>) {
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) => {
+                    let Ok(json) = serde_json::to_string(&event) else {
+                        continue;
+                    };
+                    if socket.send(Message::Text(json.into())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            // Clients only listen; stop once they hang up
+            message = socket.recv() => {
+                if !matches!(message, Some(Ok(_))) {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Send goods to another player's node, returning once the trade is done
+#[cfg(feature = "network")]
+async fn send_trade(
+    State(state): State<AppState>,
+    Json(request): Json<TradeRequest>,
+) -> Result<Json<String>, (StatusCode, String)> {
+    let endpoint_id = request
+        .endpoint_id
+        .parse()
+        .map_err(|_| (StatusCode::BAD_REQUEST, "Invalid Endpoint ID".to_string()))?;
+    request
+        .trade_item
+        .check(state.engine.lock().await.get_player())
+        .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))?;
+
+    let mut stream = state.node.trade(endpoint_id, request.trade_item);
+    let mut outcome = Err((StatusCode::BAD_GATEWAY, "Trade closed early".to_string()));
+    while let Some(event) = stream.next().await {
+        match event {
+            TradeEvent::TradeAccepted { trade_item } => {
+                outcome = Ok(Json(format!("Sent {}", trade_item.describe())));
+            }
+            TradeEvent::Closed { error: Some(err) } => {
+                outcome = Err((StatusCode::BAD_GATEWAY, err));
+            }
+            _ => {}
+        }
+    }
+
+    save(&state.engine).await;
+    outcome
+}
@@ -0,0 +1,9 @@
+#[cfg(feature = "server")]
+pub mod api;
+#[cfg(all(feature = "server", feature = "network"))]
+pub mod relay;
+
+#[cfg(feature = "server")]
+pub use api::{ServerEvent, serve};
+#[cfg(all(feature = "server", feature = "network"))]
+pub use relay::relay;
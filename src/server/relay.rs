@@ -0,0 +1,305 @@
+use anyhow::{Context, Result, bail};
+use axum::{
+    Router,
+    extract::{
+        State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+    routing::get,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::SocketAddr,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+use tokio::sync::{mpsc, oneshot};
+use tokio_stream::StreamExt;
+
+use crate::network::trade_protocol::{TradeItem, TradeNode, TradeWallet, WalletFuture};
+
+/// How long the browser has to settle its side of a trade
+const ANSWER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Messages the relay sends to the browser
+#[derive(Debug, Clone, Serialize)]
+pub enum RelayMessage {
+    /// The Endpoint ID other players send trades to
+    Node { endpoint_id: String },
+    /// A peer sent goods: add them to the farm, then `Answer` with the id
+    Receive { id: u64, trade_item: Box<TradeItem> },
+    /// Progress of a trade, for the trade log
+    Trade(String),
+    /// A trade sent with `SendTrade` is over. If `returned`, the peer didn't take
+    /// the goods and they go back to the farm. Answer with `Settled`.
+    TradeSettled { id: u64, returned: bool },
+}
+
+/// Messages the browser sends to the relay
+#[derive(Debug, Clone, Deserialize)]
+pub enum ClientMessage {
+    /// Send goods to another player, once the browser has taken them from its farm.
+    /// The browser keeps them under `id` until the trade is settled.
+    SendTrade {
+        id: u64,
+        endpoint_id: String,
+        trade_item: Box<TradeItem>,
+    },
+    /// Outcome of a `Receive`; an error refuses the trade
+    Answer { id: u64, error: Option<String> },
+    /// The browser has dealt with a `TradeSettled`
+    Settled { id: u64 },
+}
+
+/// Wallet backed by the farm of the browser connected to the relay
+#[derive(Debug, Default)]
+struct BrowserWallet {
+    client: Mutex<Option<mpsc::UnboundedSender<RelayMessage>>>,
+    pending: Mutex<HashMap<u64, oneshot::Sender<Option<String>>>>,
+    next_id: AtomicU64,
+    /// Ended trades the browser hasn't confirmed, by id, with whether the goods came back
+    unsettled: Mutex<BTreeMap<u64, bool>>,
+}
+
+impl BrowserWallet {
+    /// Attach a browser, unless another one is already connected
+    fn connect(&self, client: mpsc::UnboundedSender<RelayMessage>) -> bool {
+        let mut current = self.client.lock().unwrap();
+        if current.as_ref().is_some_and(|current| !current.is_closed()) {
+            return false;
+        }
+
+        // Settle trades that ended while the browser was away
+        for (&id, &returned) in self.unsettled.lock().unwrap().iter() {
+            let _ = client.send(RelayMessage::TradeSettled { id, returned });
+        }
+        *current = Some(client);
+
+        true
+    }
+
+    /// Detach the browser, failing any trade waiting on it
+    fn disconnect(&self) {
+        *self.client.lock().unwrap() = None;
+        self.pending.lock().unwrap().clear();
+    }
+
+    fn notify(&self, message: RelayMessage) {
+        if let Some(client) = self.client.lock().unwrap().as_ref() {
+            let _ = client.send(message);
+        }
+    }
+
+    /// Send the browser a request and wait for its answer
+    async fn ask(&self, request: impl FnOnce(u64) -> RelayMessage) -> Result<()> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (answer_tx, answer_rx) = oneshot::channel();
+        self.pending.lock().unwrap().insert(id, answer_tx);
+
+        let sent = self
+            .client
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|client| client.send(request(id)).is_ok());
+        if !sent {
+            self.pending.lock().unwrap().remove(&id);
+            bail!("The player is offline");
+        }
+
+        let answer = tokio::time::timeout(ANSWER_TIMEOUT, answer_rx).await;
+        self.pending.lock().unwrap().remove(&id);
+
+        match answer {
+            Ok(Ok(None)) => Ok(()),
+            Ok(Ok(Some(reason))) => bail!(reason),
+            Ok(Err(_)) => bail!("The player went offline"),
+            Err(_) => bail!("The player didn't answer in time"),
+        }
+    }
+
+    fn answer(&self, id: u64, error: Option<String>) {
+        if let Some(answer) = self.pending.lock().unwrap().remove(&id) {
+            let _ = answer.send(error);
+        }
+    }
+
+    /// Tell the browser a trade is over, repeating it on reconnect until confirmed
+    fn settle(&self, id: u64, returned: bool) {
+        self.unsettled.lock().unwrap().insert(id, returned);
+        self.notify(RelayMessage::TradeSettled { id, returned });
+    }
+
+    fn confirm_settled(&self, id: u64) {
+        self.unsettled.lock().unwrap().remove(&id);
+    }
+}
+
+impl TradeWallet for BrowserWallet {
+    fn receive<'a>(&'a self, trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        Box::pin(self.ask(|id| RelayMessage::Receive {
+            id,
+            trade_item: Box::new(trade_item.clone()),
+        }))
+    }
+
+    /// The browser sends goods with `SendTrade`, each trade holding its own `Escrow`
+    fn give<'a>(&'a self, _trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        Box::pin(async { bail!("The relay only sends trades from the browser") })
+    }
+}
+
+/// Goods the browser took out of its farm for one trade. They stay with the
+/// browser, which puts them back itself if the trade is settled as returned.
+#[derive(Debug, Default)]
+struct Escrow {
+    returned: AtomicBool,
+}
+
+impl TradeWallet for Escrow {
+    fn receive<'a>(&'a self, _trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        Box::pin(async { bail!("An escrow only holds goods being sent") })
+    }
+
+    fn give<'a>(&'a self, _trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn refund<'a>(&'a self, _trade_item: &'a TradeItem) -> WalletFuture<'a> {
+        self.returned.store(true, Ordering::Relaxed);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[derive(Clone)]
+struct RelayState {
+    node: TradeNode,
+    wallet: Arc<BrowserWallet>,
+}
+
+/// Run a trade node on behalf of a browser player until Ctrl+C.
+///
+/// The browser connects to `/api/relay` on localhost and keeps its farm; the relay
+/// only carries trades between it and the P2P network.
+pub fn relay(port: u16) -> Result<()> {
+    let runtime = tokio::runtime::Runtime::new().context("Failed to create Tokio runtime")?;
+    runtime.block_on(run(port))
+}
+
+async fn run(port: u16) -> Result<()> {
+    let wallet = Arc::new(BrowserWallet::default());
+    let node = TradeNode::spawn_relay(wallet.clone()).await?;
+    println!("🔗 Relay node initialized!");
+    println!("📋 Endpoint ID: {}", node.get_endpoint().id());
+
+    let mut trades = node.accept();
+    tokio::spawn({
+        let wallet = wallet.clone();
+        async move {
+            while let Some(event) = trades.next().await {
+                wallet.notify(RelayMessage::Trade(event.summary()));
+            }
+        }
+    });
+
+    let app = Router::new()
+        .route("/api/relay", get(connect))
+        .with_state(RelayState { node, wallet });
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to listen on {}", addr))?;
+    println!("🌐 Relaying trades for ws://{}/api/relay", addr);
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            tokio::signal::ctrl_c().await.ok();
+        })
+        .await
+        .context("Server error")?;
+
+    Ok(())
+}
+
+async fn connect(State(state): State<RelayState>, ws: WebSocketUpgrade) -> Response {
+    ws.on_upgrade(move |socket| run_client(socket, state))
+}
+
+async fn send(socket: &mut WebSocket, message: &RelayMessage) -> Result<()> {
+    let json = serde_json::to_string(message)?;
+    socket.send(Message::Text(json.into())).await?;
+    Ok(())
+}
+
+async fn run_client(mut socket: WebSocket, state: RelayState) {
+    let (client_tx, mut client_rx) = mpsc::unbounded_channel();
+    if !state.wallet.connect(client_tx) {
+        let busy = RelayMessage::Trade("❌ Another player is using this relay".to_string());
+        let _ = send(&mut socket, &busy).await;
+        return;
+    }
+
+    let node = RelayMessage::Node {
+        endpoint_id: state.node.get_endpoint().id().to_string(),
+    };
+    if send(&mut socket, &node).await.is_ok() {
+        loop {
+            tokio::select! {
+                Some(message) = client_rx.recv() => {
+                    if send(&mut socket, &message).await.is_err() {
+                        break;
+                    }
+                }
+                message = socket.recv() => match message {
+                    Some(Ok(Message::Text(text))) => match serde_json::from_str(&text) {
+                        Ok(ClientMessage::Answer { id, error }) => state.wallet.answer(id, error),
+                        Ok(ClientMessage::Settled { id }) => state.wallet.confirm_settled(id),
+                        // Trading waits on this loop for the browser's answers
+                        Ok(ClientMessage::SendTrade { id, endpoint_id, trade_item }) => {
+                            tokio::spawn(send_trade(state.clone(), id, endpoint_id, *trade_item));
+                        }
+                        Err(err) => {
+                            let invalid = RelayMessage::Trade(format!("❌ Invalid message: {}", err));
+                            if send(&mut socket, &invalid).await.is_err() {
+                                break;
+                            }
+                        }
+                    },
+                    Some(Ok(_)) => {}
+                    _ => break,
+                },
+            }
+        }
+    }
+
+    state.wallet.disconnect();
+}
+
+/// Send the browser's goods to a peer, reporting progress to the browser
+async fn send_trade(state: RelayState, id: u64, endpoint_id: String, trade_item: TradeItem) {
+    let Ok(endpoint_id) = endpoint_id.parse() else {
+        state
+            .wallet
+            .notify(RelayMessage::Trade("❌ Invalid Endpoint ID".to_string()));
+        state.wallet.settle(id, true);
+        return;
+    };
+
+    let escrow = Arc::new(Escrow::default());
+    let mut stream = state
+        .node
+        .trade_from(endpoint_id, trade_item, escrow.clone());
+    while let Some(event) = stream.next().await {
+        state.wallet.notify(RelayMessage::Trade(event.summary()));
+    }
+
+    state
+        .wallet
+        .settle(id, escrow.returned.load(Ordering::Relaxed));
+}
@@ -1,18 +1,76 @@
-use crate::core::{GameEngine, types::*};
+use crate::core::{
+    Calendar, GameEngine, SaveBundle,
+    animal::{AnimalKind, BuildingKind},
+    crafting::recipe_catalog,
+    machine::machine_products,
+    persistence::SaveSlots,
+    season::Season,
+    trade::TradeItem,
+    types::*,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
+use super::storage::LocalStorage;
+
+type JsGameCommand = <GameCommand as Tsify>::JsType;
+type JsGameResult = <GameResult as Tsify>::JsType;
+type JsGameInfo = <GameInfo as Tsify>::JsType;
+type JsSeason = <Season as Tsify>::JsType;
+type JsBuildingKind = <BuildingKind as Tsify>::JsType;
+type JsAnimalKind = <AnimalKind as Tsify>::JsType;
+type JsTimeMode = <TimeMode as Tsify>::JsType;
+type JsTradeItem = <TradeItem as Tsify>::JsType;
+type JsCalendar = <Calendar as Tsify>::JsType;
+
+/// A seed on sale in the shop
+#[derive(Serialize, Tsify)]
+pub struct SeedInfo {
+    pub name: String,
+    pub cost: u32,
+    pub growth_days: u8,
+    pub sell_price: u32,
+    pub seasons: Vec<Season>,
+    pub regrow_days: Option<u8>,
+    pub icon: String,
+}
+
+/// An item on sale in the shop
+#[derive(Serialize, Tsify)]
+pub struct ShopItem {
+    pub name: String,
+    pub cost: u32,
+}
+
 #[wasm_bindgen]
 pub struct WasmGameEngine {
     engine: GameEngine,
+    /// Slot saved to after every command that changes the game
+    autosave_slot: Option<String>,
 }
 
 #[wasm_bindgen]
 impl WasmGameEngine {
     #[wasm_bindgen(constructor)]
     pub fn new(player_name: &str) -> Self {
-        Self {
-            engine: GameEngine::new_game(player_name),
-        }
+        Self::from(GameEngine::new_game(player_name))
+    }
+
+    /// Create a new game with a custom calendar
+    #[wasm_bindgen(js_name = newWithCalendar)]
+    pub fn new_with_calendar(
+        player_name: &str,
+        calendar: JsCalendar,
+    ) -> Result<WasmGameEngine, JsValue> {
+        // Deserializing a calendar checks it with `Calendar::new`
+        let calendar: Calendar = from_js(calendar)
+            .map_err(|e| JsValue::from_str(&format!("Invalid calendar: {}", e)))?;
+
+        Ok(Self::from(GameEngine::new_game_with_calendar(
+            player_name,
+            calendar,
+        )))
     }
 
     /// Create a game from JSON state
@@ -20,7 +78,7 @@ impl WasmGameEngine {
     pub fn from_json(json: &str) -> Result<WasmGameEngine, JsValue> {
         let engine: GameEngine = serde_json::from_str(json)
             .map_err(|e| JsValue::from_str(&format!("Failed to parse game state: {}", e)))?;
-        Ok(Self { engine })
+        Ok(Self::from(engine))
     }
 
     /// Export game state as JSON
@@ -30,67 +88,106 @@ impl WasmGameEngine {
             .map_err(|e| JsValue::from_str(&format!("Failed to serialize game state: {}", e)))
     }
 
-    /// Execute a command and get the result as JSON
+    /// Export game state as a portable, checksummed save bundle
+    #[wasm_bindgen(js_name = exportSave)]
+    pub fn export_save(&self) -> Result<String, JsValue> {
+        SaveBundle::export(&self.engine)
+            .map_err(|e| JsValue::from_str(&format!("Failed to export save: {}", e)))
+    }
+
+    /// Create a game from a portable save bundle
+    #[wasm_bindgen(js_name = importSave)]
+    pub fn import_save(bundle_json: &str) -> Result<WasmGameEngine, JsValue> {
+        let engine = SaveBundle::import(bundle_json)
+            .map_err(|e| JsValue::from_str(&format!("Failed to import save: {}", e)))?;
+        Ok(Self::from(engine))
+    }
+
+    /// Execute a command and get its result
     #[wasm_bindgen(js_name = executeCommand)]
-    pub fn execute_command(&mut self, command_json: &str) -> String {
-        let command: GameCommand = match serde_json::from_str(command_json) {
-            Ok(cmd) => cmd,
-            Err(e) => {
-                return serde_json::to_string(&GameResult::Error(format!(
-                    "Invalid command: {}",
-                    e
-                )))
-                .unwrap_or_else(|_| r#"{"Error":"Invalid command"}"#.to_string());
-            }
+    pub fn execute_command(&mut self, command: JsGameCommand) -> Result<JsGameResult, JsValue> {
+        let result = match from_js::<GameCommand>(command) {
+            Ok(command) => self.engine.execute(command),
+            Err(e) => GameResult::Error(format!("Invalid command: {}", e)),
         };
-
-        let result = self.engine.execute(command);
-        serde_json::to_string(&result)
-            .unwrap_or_else(|_| r#"{"Error":"Serialization failed"}"#.to_string())
+        self.finish(result)
     }
 
-    /// Get current game information as JSON
+    /// Get current game information
     #[wasm_bindgen(js_name = getInfo)]
-    pub fn get_info(&self) -> String {
-        let info = self.engine.get_info();
-        serde_json::to_string(&info).unwrap_or_else(|_| "{}".to_string())
+    pub fn get_info(&self) -> Result<JsGameInfo, JsValue> {
+        to_js(&self.engine.get_info())
     }
 
     // Convenience methods for common operations
 
     #[wasm_bindgen]
-    pub fn sleep(&mut self) -> String {
+    pub fn sleep(&mut self) -> Result<JsGameResult, JsValue> {
         let result = self.engine.execute(GameCommand::Sleep);
-        serde_json::to_string(&result)
-            .unwrap_or_else(|_| r#"{"Error":"Serialization failed"}"#.to_string())
+        self.finish(result)
     }
 
     #[wasm_bindgen(js_name = plantCrop)]
-    pub fn plant_crop(&mut self, crop_index: usize) -> String {
-        let result = self.engine.execute(GameCommand::PlantCrop { crop_index });
-        serde_json::to_string(&result)
-            .unwrap_or_else(|_| r#"{"Error":"Serialization failed"}"#.to_string())
+    pub fn plant_crop(
+        &mut self,
+        crop_index: usize,
+        plot: Option<usize>,
+    ) -> Result<JsGameResult, JsValue> {
+        let result = self
+            .engine
+            .execute(GameCommand::PlantCrop { crop_index, plot });
+        self.finish(result)
     }
 
     #[wasm_bindgen(js_name = waterCrops)]
-    pub fn water_crops(&mut self) -> String {
+    pub fn water_crops(&mut self) -> Result<JsGameResult, JsValue> {
         let result = self.engine.execute(GameCommand::WaterCrops);
-        serde_json::to_string(&result)
-            .unwrap_or_else(|_| r#"{"Error":"Serialization failed"}"#.to_string())
+        self.finish(result)
     }
 
     #[wasm_bindgen(js_name = harvestCrops)]
-    pub fn harvest_crops(&mut self) -> String {
+    pub fn harvest_crops(&mut self) -> Result<JsGameResult, JsValue> {
         let result = self.engine.execute(GameCommand::HarvestCrops);
-        serde_json::to_string(&result)
-            .unwrap_or_else(|_| r#"{"Error":"Serialization failed"}"#.to_string())
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = gatherCrops)]
+    pub fn gather_crops(&mut self) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::GatherCrops);
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = sellItem)]
+    pub fn sell_item(&mut self, item_name: &str, quantity: u32) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::SellItem {
+            item_name: item_name.to_string(),
+            quantity,
+        });
+        self.finish(result)
+    }
+
+    pub fn craft(&mut self, recipe_id: &str) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::Craft {
+            recipe_id: recipe_id.to_string(),
+        });
+        self.finish(result)
     }
 
     #[wasm_bindgen(js_name = advanceDay)]
-    pub fn advance_day(&mut self) -> String {
+    pub fn advance_day(&mut self) -> Result<JsGameResult, JsValue> {
         let result = self.engine.execute(GameCommand::AdvanceDay);
-        serde_json::to_string(&result)
-            .unwrap_or_else(|_| r#"{"Error":"Serialization failed"}"#.to_string())
+        self.finish(result)
+    }
+
+    /// Let real time pass, returning the events of any new days. Call this from a
+    /// timer to make days advance in real-time mode.
+    #[wasm_bindgen(unchecked_return_type = "GameEvent[]")]
+    pub fn tick(&mut self) -> Result<JsValue, JsValue> {
+        let events = self.engine.tick(chrono::Utc::now());
+        if !events.is_empty() {
+            self.autosave()?;
+        }
+        to_js_value(&events)
     }
 
     // Getters for specific game state
@@ -101,41 +198,317 @@ impl WasmGameEngine {
     }
 
     #[wasm_bindgen(js_name = getCurrentSeason)]
-    pub fn get_current_season(&self) -> String {
-        format!("{:?}", self.engine.get_current_season())
+    pub fn get_current_season(&self) -> Result<JsSeason, JsValue> {
+        to_js(&self.engine.get_current_season())
     }
 
     #[wasm_bindgen(js_name = buySeed)]
-    pub fn buy_seed(&mut self, seed_name: &str) -> String {
+    pub fn buy_seed(&mut self, seed_name: &str) -> Result<JsGameResult, JsValue> {
         let result = self.engine.execute(GameCommand::BuySeed {
             seed_name: seed_name.to_string(),
         });
-        serde_json::to_string(&result)
-            .unwrap_or_else(|_| r#"{"Error":"Serialization failed"}"#.to_string())
+        self.finish(result)
     }
 
-    #[wasm_bindgen(js_name = getAvailableSeeds)]
-    pub fn get_available_seeds(&self) -> String {
-        use crate::core::crop::get_seasonal_crops;
-        let current_season = self.engine.get_current_season();
-        let available_crops = get_seasonal_crops(current_season);
+    #[wasm_bindgen(js_name = enterCropContest)]
+    pub fn enter_crop_contest(&mut self, field_index: usize) -> Result<JsGameResult, JsValue> {
+        let result = self
+            .engine
+            .execute(GameCommand::EnterCropContest { field_index });
+        self.finish(result)
+    }
 
-        // Convert to a simpler format with prices
-        let seeds_info: Vec<serde_json::Value> = available_crops
+    #[wasm_bindgen(js_name = buyItem)]
+    pub fn buy_item(&mut self, item_name: &str) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::BuyItem {
+            item_name: item_name.to_string(),
+        });
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = applyFertilizer)]
+    pub fn apply_fertilizer(
+        &mut self,
+        plot: usize,
+        item_name: &str,
+    ) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::ApplyFertilizer {
+            plot,
+            item_name: item_name.to_string(),
+        });
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = placeSprinkler)]
+    pub fn place_sprinkler(
+        &mut self,
+        plot: usize,
+        item_name: &str,
+    ) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::PlaceSprinkler {
+            plot,
+            item_name: item_name.to_string(),
+        });
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = removeSprinkler)]
+    pub fn remove_sprinkler(&mut self, plot: usize) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::RemoveSprinkler { plot });
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = placeMachine)]
+    pub fn place_machine(&mut self, plot: usize, item_name: &str) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::PlaceMachine {
+            plot,
+            item_name: item_name.to_string(),
+        });
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = removeMachine)]
+    pub fn remove_machine(&mut self, plot: usize) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::RemoveMachine { plot });
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = loadMachine)]
+    pub fn load_machine(&mut self, plot: usize, item_name: &str) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::LoadMachine {
+            plot,
+            item_name: item_name.to_string(),
+        });
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = collectMachineProducts)]
+    pub fn collect_machine_products(&mut self) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::CollectMachineProducts);
+        self.finish(result)
+    }
+
+    pub fn build(&mut self, building: JsBuildingKind) -> Result<JsGameResult, JsValue> {
+        let result = match from_js::<BuildingKind>(building) {
+            Ok(building) => self.engine.execute(GameCommand::Build { building }),
+            Err(e) => GameResult::Error(format!("Unknown building: {}", e)),
+        };
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = buyAnimal)]
+    pub fn buy_animal(&mut self, kind: JsAnimalKind, name: &str) -> Result<JsGameResult, JsValue> {
+        let result = match from_js::<AnimalKind>(kind) {
+            Ok(kind) => self.engine.execute(GameCommand::BuyAnimal {
+                kind,
+                name: name.to_string(),
+            }),
+            Err(e) => GameResult::Error(format!("Unknown animal: {}", e)),
+        };
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = feedAnimals)]
+    pub fn feed_animals(&mut self) -> Result<JsGameResult, JsValue> {
+        let result = self.engine.execute(GameCommand::FeedAnimals);
+        self.finish(result)
+    }
+
+    /// Set the time mode ("RealTime", "TurnBased" or "Paused")
+    #[wasm_bindgen(js_name = setTimeMode)]
+    pub fn set_time_mode(
+        &mut self,
+        mode: JsTimeMode,
+        minutes_per_day: Option<u32>,
+    ) -> Result<JsGameResult, JsValue> {
+        let result = match from_js::<TimeMode>(mode) {
+            Ok(mode) => self.engine.execute(GameCommand::SetTimeMode {
+                mode,
+                minutes_per_day: minutes_per_day.map(i64::from),
+            }),
+            Err(e) => GameResult::Error(format!("Unknown time mode: {}", e)),
+        };
+        self.finish(result)
+    }
+
+    #[wasm_bindgen(js_name = getAvailableSeeds, unchecked_return_type = "SeedInfo[]")]
+    pub fn get_available_seeds(&self) -> Result<JsValue, JsValue> {
+        let seeds: Vec<SeedInfo> = self
+            .engine
+            .available_seeds()
             .iter()
-            .map(|crop| {
-                let seed_cost = (crop.sell_price as f32 * 0.5) as u32;
-                serde_json::json!({
-                    "name": crop.name,
-                    "cost": seed_cost,
-                    "growth_days": crop.growth_days,
-                    "sell_price": crop.sell_price,
-                    "seasons": crop.seasons,
-                    "icon": crop.icon
-                })
+            .map(|crop| SeedInfo {
+                name: crop.name.clone(),
+                cost: crop.seed_price(),
+                growth_days: crop.growth_days,
+                sell_price: crop.sell_price,
+                seasons: crop.seasons.clone(),
+                regrow_days: crop.regrow_days,
+                icon: crop.icon.clone(),
             })
             .collect();
 
-        serde_json::to_string(&seeds_info).unwrap_or_else(|_| "[]".to_string())
+        to_js_value(&seeds)
+    }
+
+    #[wasm_bindgen(js_name = getShopItems, unchecked_return_type = "ShopItem[]")]
+    pub fn get_shop_items(&self) -> Result<JsValue, JsValue> {
+        let items: Vec<ShopItem> = self
+            .engine
+            .shop_items()
+            .into_iter()
+            .map(|(name, cost)| ShopItem { name, cost })
+            .collect();
+
+        to_js_value(&items)
+    }
+
+    #[wasm_bindgen(js_name = getRecipes, unchecked_return_type = "Recipe[]")]
+    pub fn get_recipes(&self) -> Result<JsValue, JsValue> {
+        to_js_value(&recipe_catalog())
+    }
+
+    #[wasm_bindgen(js_name = getMachineProducts, unchecked_return_type = "MachineProduct[]")]
+    pub fn get_machine_products(&self) -> Result<JsValue, JsValue> {
+        to_js_value(&machine_products())
+    }
+
+    /// Check the player has the goods in a trade item
+    #[wasm_bindgen(js_name = checkTrade)]
+    pub fn check_trade(&self, trade_item: JsTradeItem) -> Result<(), JsValue> {
+        parse_trade_item(trade_item)?
+            .check(self.engine.get_player())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Add goods received from a trade partner, failing if the farm can't take them
+    #[wasm_bindgen(js_name = receiveTrade)]
+    pub fn receive_trade(&mut self, trade_item: JsTradeItem) -> Result<(), JsValue> {
+        parse_trade_item(trade_item)?
+            .receive(self.engine.get_player_mut())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        self.autosave()
+    }
+
+    /// Take out goods to send to a trade partner; `receiveTrade` puts them back if the trade fails
+    #[wasm_bindgen(js_name = giveTrade)]
+    pub fn give_trade(&mut self, trade_item: JsTradeItem) -> Result<(), JsValue> {
+        parse_trade_item(trade_item)?
+            .give(self.engine.get_player_mut())
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        self.autosave()
+    }
+
+    // Save slots in the browser's localStorage
+
+    /// Load the game saved in a slot, autosaving back to it, or `undefined` if the slot is empty
+    #[wasm_bindgen(js_name = loadSlot)]
+    pub fn load_slot(slot: &str) -> Result<Option<WasmGameEngine>, JsValue> {
+        let engine = saves().load(slot).map_err(to_js_error)?;
+        Ok(engine.map(|engine| Self::from(engine).with_autosave(slot)))
+    }
+
+    /// Save the game to a slot, backing up the slot's save if it is from another day
+    pub fn save(&self, slot: &str) -> Result<(), JsValue> {
+        saves().save(slot, &self.engine).map_err(to_js_error)
+    }
+
+    /// Save to a slot after every command that changes the game, or stop autosaving
+    #[wasm_bindgen(js_name = setAutosave)]
+    pub fn set_autosave(&mut self, slot: Option<String>) -> Result<(), JsValue> {
+        self.autosave_slot = slot;
+        self.autosave()
+    }
+
+    /// Names of the slots holding a save
+    #[wasm_bindgen(js_name = listSlots)]
+    pub fn list_slots() -> Result<Vec<String>, JsValue> {
+        saves().slots().map_err(to_js_error)
+    }
+
+    /// Delete the save in a slot, keeping its backups
+    #[wasm_bindgen(js_name = deleteSlot)]
+    pub fn delete_slot(slot: &str) -> Result<(), JsValue> {
+        saves().delete(slot).map_err(to_js_error)
+    }
+
+    /// Day of each backup kept for a slot, newest first, or `null` for a backup
+    /// that can't be read
+    #[wasm_bindgen(js_name = listBackups, unchecked_return_type = "(number | null)[]")]
+    pub fn list_backups(slot: &str) -> Result<JsValue, JsValue> {
+        let days: Vec<Option<u32>> = saves()
+            .backups(slot)
+            .map_err(to_js_error)?
+            .into_iter()
+            .map(Result::ok)
+            .collect();
+        to_js_value(&days)
+    }
+
+    /// Replace a slot's save with one of its backups, numbered from 1 (newest)
+    #[wasm_bindgen(js_name = restoreBackup)]
+    pub fn restore_backup(slot: &str, backup: usize) -> Result<WasmGameEngine, JsValue> {
+        let engine = saves().restore_backup(slot, backup).map_err(to_js_error)?;
+        Ok(Self::from(engine).with_autosave(slot))
+    }
+}
+
+impl From<GameEngine> for WasmGameEngine {
+    fn from(engine: GameEngine) -> Self {
+        Self {
+            engine,
+            autosave_slot: None,
+        }
     }
 }
+
+impl WasmGameEngine {
+    fn with_autosave(mut self, slot: &str) -> Self {
+        self.autosave_slot = Some(slot.to_string());
+        self
+    }
+
+    fn autosave(&self) -> Result<(), JsValue> {
+        match &self.autosave_slot {
+            Some(slot) => self.save(slot),
+            None => Ok(()),
+        }
+    }
+
+    /// Autosave after a successful command, then hand its result to JS
+    fn finish(&self, result: GameResult) -> Result<JsGameResult, JsValue> {
+        if matches!(result, GameResult::Success(_)) {
+            self.autosave()?;
+        }
+        to_js(&result)
+    }
+}
+
+fn saves() -> SaveSlots<LocalStorage> {
+    SaveSlots::new(LocalStorage)
+}
+
+fn to_js_error(err: anyhow::Error) -> JsValue {
+    JsValue::from_str(&format!("{:#}", err))
+}
+
+/// Convert a value to a plain JS object, with maps as objects rather than `Map`s
+fn to_js_value<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize: {}", e)))
+}
+
+/// Convert a value to its generated TypeScript type
+fn to_js<T: Serialize + Tsify>(value: &T) -> Result<T::JsType, JsValue> {
+    to_js_value(value).map(JsCast::unchecked_into)
+}
+
+fn from_js<T: DeserializeOwned>(value: impl Into<JsValue>) -> Result<T, serde_wasm_bindgen::Error> {
+    serde_wasm_bindgen::from_value(value.into())
+}
+
+fn parse_trade_item(trade_item: JsTradeItem) -> Result<TradeItem, JsValue> {
+    from_js(trade_item)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse trade item: {}", e)))
+}
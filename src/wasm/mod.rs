@@ -1,5 +1,7 @@
 #[cfg(feature = "wasm")]
 pub mod game_wrapper;
+#[cfg(feature = "wasm")]
+pub mod storage;
 
 #[cfg(feature = "wasm")]
 pub use game_wrapper::WasmGameEngine;
@@ -0,0 +1,60 @@
+use anyhow::{Context, Result, anyhow};
+use wasm_bindgen::JsValue;
+use web_sys::Storage;
+
+use crate::core::persistence::SaveStorage;
+
+const KEY_PREFIX: &str = "harvest-";
+
+/// Stores each key in the browser's localStorage, e.g. `harvest-game-state`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalStorage;
+
+impl LocalStorage {
+    fn storage() -> Result<Storage> {
+        web_sys::window()
+            .context("No browser window")?
+            .local_storage()
+            .map_err(js_error)?
+            .context("localStorage is not available")
+    }
+}
+
+impl SaveStorage for LocalStorage {
+    fn read(&self, key: &str) -> Result<Option<String>> {
+        Self::storage()?
+            .get_item(&format!("{}{}", KEY_PREFIX, key))
+            .map_err(js_error)
+    }
+
+    fn write(&self, key: &str, value: &str) -> Result<()> {
+        Self::storage()?
+            .set_item(&format!("{}{}", KEY_PREFIX, key), value)
+            .map_err(js_error)
+            .context("Failed to write to localStorage, it may be full")
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        Self::storage()?
+            .remove_item(&format!("{}{}", KEY_PREFIX, key))
+            .map_err(js_error)
+    }
+
+    fn keys(&self) -> Result<Vec<String>> {
+        let storage = Self::storage()?;
+        let length = storage.length().map_err(js_error)?;
+
+        let mut keys = Vec::new();
+        for index in 0..length {
+            if let Some(key) = storage.key(index).map_err(js_error)? {
+                keys.extend(key.strip_prefix(KEY_PREFIX).map(str::to_string));
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+fn js_error(err: JsValue) -> anyhow::Error {
+    anyhow!("{:?}", err)
+}
@@ -1,7 +1,32 @@
-import { useGame } from "./useGame";
+import { DEFAULT_RELAY_URL, useGame } from "./useGame";
 import "./App.css";
-import { useState } from "react";
-import type { SeedInfo } from "./types";
+import { useMemo, useState } from "react";
+import type {
+  Crop,
+  MachineProduct,
+  Recipe,
+  SeedInfo,
+  ShopItem,
+  TradeItem,
+} from "./types";
+
+// Mirrors Crop::days_to_mature in the engine
+function daysToMature(crop: Crop) {
+  const base =
+    crop.regrow_days != null && (crop.times_harvested ?? 0) > 0
+      ? crop.regrow_days
+      : crop.growth_days;
+  return Math.max(1, base + (crop.soil_modifier ?? 0));
+}
+
+const QUALITY_ICONS = { Normal: "", Silver: "🥈", Gold: "🥇" };
+
+const ANIMAL_ICONS: Record<string, string> = {
+  Chicken: "🐔",
+  Cow: "🐄",
+};
+
+const MACHINE_NAMES = ["Keg", "Preserves Jar", "Mill"];
 
 function App() {
   const {
@@ -13,11 +38,39 @@ function App() {
     plantCrop,
     waterCrops,
     harvestCrops,
+    gatherCrops,
+    sellItem,
+    craft,
+    getRecipes,
+    placeMachine,
+    removeMachine,
+    loadMachine,
+    collectMachineProducts,
+    getMachineProducts,
+    build,
+    buyAnimal,
+    feedAnimals,
     advanceDay,
     buySeed,
+    buyItem,
+    applyFertilizer,
+    placeSprinkler,
+    removeSprinkler,
     getAvailableSeeds,
+    getShopItems,
     resetGame,
+    exportSave,
+    importSave,
+    slot,
+    slots,
+    backups,
+    switchSlot,
+    restoreBackup,
     clearMessage,
+    relayEndpointId,
+    tradeLog,
+    connectRelay,
+    sendTrade,
     showNameInput,
     playerName,
     setPlayerName,
@@ -26,6 +79,19 @@ function App() {
 
   const [showMarket, setShowMarket] = useState(false);
   const [availableSeeds, setAvailableSeeds] = useState<SeedInfo[]>([]);
+  const [shopItems, setShopItems] = useState<ShopItem[]>([]);
+  const [selectedTool, setSelectedTool] = useState("");
+  const [relayUrl, setRelayUrl] = useState(DEFAULT_RELAY_URL);
+  const [peerId, setPeerId] = useState("");
+  // "coins", "item:<name>" or "crop:<inventory index>"
+  const [tradeGoods, setTradeGoods] = useState("coins");
+  const [tradeAmount, setTradeAmount] = useState(1);
+  const [newSlot, setNewSlot] = useState("");
+  const recipes: Recipe[] = useMemo(() => getRecipes(), [getRecipes]);
+  const machineProducts: MachineProduct[] = useMemo(
+    () => getMachineProducts(),
+    [getMachineProducts],
+  );
 
   if (loading) {
     return (
@@ -84,12 +150,87 @@ function App() {
 
   const energyPercentage = (gameInfo.player_energy / gameInfo.max_energy) * 100;
 
+  // Mirrors Skills::energy_cost: farming levels make tasks cheaper, down to 5
+  const energyCost = (crop: Crop) =>
+    Math.max(
+      crop.energy_cost - gameInfo.energy_discount,
+      Math.min(5, crop.energy_cost),
+    );
+
   const handleOpenMarket = () => {
     const seeds = getAvailableSeeds();
     setAvailableSeeds(seeds);
+    setShopItems(getShopItems());
     setShowMarket(true);
   };
 
+  const handleImportSave = async (file: File | undefined) => {
+    if (!file) return;
+    importSave(await file.text());
+  };
+
+  const tools = Object.keys(gameInfo.items).filter(
+    (name) =>
+      name.endsWith("Fertilizer") ||
+      name.endsWith("Sprinkler") ||
+      MACHINE_NAMES.includes(name),
+  );
+  const tool = tools.includes(selectedTool) ? selectedTool : tools[0];
+
+  const handlePlotClick = (plot: number) => {
+    if (gameInfo.sprinklers.some((sprinkler) => sprinkler.plot === plot)) {
+      removeSprinkler(plot);
+    } else if (gameInfo.machines.some((machine) => machine.plot === plot)) {
+      removeMachine(plot);
+    } else if (tool?.endsWith("Sprinkler")) {
+      placeSprinkler(plot, tool);
+    } else if (tool && MACHINE_NAMES.includes(tool)) {
+      placeMachine(plot, tool);
+    } else if (tool) {
+      applyFertilizer(plot, tool);
+    }
+  };
+
+  const plotIcon = (plot: number) => {
+    const sprinkler = gameInfo.sprinklers.find((s) => s.plot === plot);
+    if (sprinkler) {
+      return sprinkler.kind === "Basic"
+        ? "💦"
+        : sprinkler.kind === "Quality"
+          ? "⛲"
+          : "🌀";
+    }
+    const machine = gameInfo.machines.find((m) => m.plot === plot);
+    if (machine) {
+      return machine.kind === "Keg"
+        ? "🛢️"
+        : machine.kind === "PreservesJar"
+          ? "🫙"
+          : "🏭";
+    }
+    return null;
+  };
+
+  const handleSendTrade = () => {
+    const [kind, value] = tradeGoods.split(/:(.*)/);
+    const tradeItem: TradeItem =
+      kind === "item"
+        ? {
+            item_type: "Item",
+            amount: tradeAmount,
+            crop: null,
+            item_name: value,
+          }
+        : kind === "crop"
+          ? {
+              item_type: "Crop",
+              amount: null,
+              crop: gameInfo.inventory[Number(value)] ?? null,
+            }
+          : { item_type: "Money", amount: tradeAmount, crop: null };
+    sendTrade(peerId.trim(), tradeItem);
+  };
+
   const handleBuySeed = (seedName: string) => {
     buySeed(seedName);
     setShowMarket(false);
@@ -99,9 +240,23 @@ function App() {
     <div className="app">
       <header className="header">
         <h1>🌾 Harvest Game</h1>
-        <button className="reset-button" onClick={resetGame}>
-          Reset Game
-        </button>
+        <div className="header-actions">
+          <button className="action-button secondary" onClick={exportSave}>
+            Export Save
+          </button>
+          <label className="action-button secondary">
+            Import Save
+            <input
+              type="file"
+              accept="application/json"
+              hidden
+              onChange={(e) => handleImportSave(e.target.files?.[0])}
+            />
+          </label>
+          <button className="reset-button" onClick={resetGame}>
+            Reset Game
+          </button>
+        </div>
       </header>
 
       {/* Message/Error Display */}
@@ -124,11 +279,36 @@ function App() {
             <p className="year-info">
               Year {gameInfo.year}, Day {gameInfo.day_in_season}
             </p>
+            <p className="year-info">
+              🕒 {String(gameInfo.hour % 24).padStart(2, "0")}:
+              {String(gameInfo.minute).padStart(2, "0")} · Shop{" "}
+              {gameInfo.shop_open ? "open" : "closed"}
+            </p>
+            <p className="year-info">
+              ⏳ {gameInfo.days_until_season_end} day(s) until{" "}
+              {gameInfo.next_season}
+            </p>
+            {gameInfo.crops_at_risk.length > 0 && (
+              <p className="year-info">
+                ⚠️ Won't mature in time: {gameInfo.crops_at_risk.join(", ")}
+              </p>
+            )}
+            {gameInfo.festival && (
+              <p className="year-info">
+                {gameInfo.festival.icon} {gameInfo.festival.name}
+              </p>
+            )}
           </div>
 
           <div className="stat-card">
             <h3>👤 {gameInfo.player_name}</h3>
             <p className="money">💰 ${gameInfo.player_money}</p>
+            <p>
+              🧑‍🌾 Farming Lv {gameInfo.farming_level} · XP{" "}
+              {gameInfo.farming_xp}
+              {gameInfo.next_level_xp !== null &&
+                ` / ${gameInfo.next_level_xp}`}
+            </p>
           </div>
 
           <div className="stat-card">
@@ -169,22 +349,26 @@ function App() {
                   >
                     <div className="crop-header">
                       <span className="crop-name">
-                        {crop.icon} {crop.name}
+                        {crop.icon} {crop.giant && "🌟 Giant "}
+                        {crop.name}
+                        {crop.plot != null && ` · Plot ${crop.plot + 1}`}
                       </span>
                       {crop.ready_harvest && (
-                        <span className="ready-badge">✓ Ready!</span>
+                        <span className="ready-badge">
+                          ✓ Ready! {QUALITY_ICONS[crop.quality ?? "Normal"]}
+                        </span>
                       )}
                     </div>
                     <div className="crop-progress">
                       <div
                         className="progress-bar"
                         style={{
-                          width: `${(crop.watered_days.length / crop.growth_days) * 100}%`,
+                          width: `${(crop.watered_days.length / daysToMature(crop)) * 100}%`,
                         }}
                       />
                     </div>
                     <p className="crop-info">
-                      {crop.watered_days.length} / {crop.growth_days} days
+                      {crop.watered_days.length} / {daysToMature(crop)} days
                     </p>
                     <p className="crop-value">Sell: ${crop.sell_price}</p>
                   </div>
@@ -193,6 +377,44 @@ function App() {
             </div>
           </div>
 
+          {/* Soil */}
+          <div className="section">
+            <h2>🟫 Soil, Sprinklers & Machines</h2>
+            <div className="soil-grid">
+              {gameInfo.plots.map((plot, index) => (
+                <button
+                  key={index}
+                  className="soil-plot"
+                  title={`Plot ${index + 1}${plot.last_family ? ` · last: ${plot.last_family}` : ""}`}
+                  onClick={() => handlePlotClick(index)}
+                  disabled={!tool && !plotIcon(index)}
+                >
+                  {plotIcon(index) ?? `${plot.fertility}%`}
+                </button>
+              ))}
+            </div>
+            {tools.length > 0 ? (
+              <p className="crop-info">
+                Click a plot to use{" "}
+                <select
+                  value={tool}
+                  onChange={(e) => setSelectedTool(e.target.value)}
+                >
+                  {tools.map((name) => (
+                    <option key={name} value={name}>
+                      {name} ({gameInfo.items[name]})
+                    </option>
+                  ))}
+                </select>
+                , or click a sprinkler or idle machine to pick it up
+              </p>
+            ) : (
+              <p className="crop-info">
+                Buy fertilizer, sprinklers or machines at the market
+              </p>
+            )}
+          </div>
+
           {/* Inventory */}
           <div className="section">
             <h2>🎒 Inventory ({gameInfo.inventory.length})</h2>
@@ -212,7 +434,7 @@ function App() {
                     <p className="crop-info">Growth: {crop.growth_days} days</p>
                     <p className="crop-value">Value: ${crop.sell_price}</p>
                     <p className="crop-seasons">
-                      {crop.seasons
+                      {(crop.seasons ?? [])
                         .map((s) => {
                           const emoji =
                             s === "Spring"
@@ -229,15 +451,298 @@ function App() {
                     <button
                       className="action-button small"
                       onClick={() => plantCrop(index)}
-                      disabled={gameInfo.player_energy < crop.energy_cost}
+                      disabled={gameInfo.player_energy < energyCost(crop)}
                     >
-                      Plant (⚡{crop.energy_cost})
+                      Plant (⚡{energyCost(crop)})
                     </button>
                   </div>
                 ))
               )}
             </div>
           </div>
+
+          {/* Workshop */}
+          <div className="section">
+            <h2>🔨 Workshop</h2>
+            <div className="crops-grid">
+              {Object.entries(gameInfo.items).map(([name, count]) => (
+                <div key={name} className="crop-card inventory-item">
+                  <span className="crop-name">
+                    {name} x{count}
+                  </span>
+                  <button
+                    className="action-button small"
+                    onClick={() => sellItem(name, count)}
+                  >
+                    Sell all
+                  </button>
+                </div>
+              ))}
+              {gameInfo.crafting.map((job, index) => (
+                <div key={index} className="crop-card">
+                  <span className="crop-name">🔨 {job.output}</span>
+                  <p className="crop-info">Ready on day {job.ready_day}</p>
+                </div>
+              ))}
+            </div>
+            <div className="crops-grid">
+              {gameInfo.machines.map((machine) => {
+                const inputs = machineProducts.filter(
+                  (product) =>
+                    product.machine === machine.kind &&
+                    (gameInfo.items[product.input] ?? 0) > 0,
+                );
+                return (
+                  <div key={machine.plot} className="crop-card">
+                    <span className="crop-name">
+                      {plotIcon(machine.plot)} {machine.kind} · Plot{" "}
+                      {machine.plot + 1}
+                    </span>
+                    {machine.processing ? (
+                      <p className="crop-info">
+                        {machine.processing.ready
+                          ? `✓ ${machine.processing.output} ready!`
+                          : `${machine.processing.output} on day ${machine.processing.ready_day}`}
+                      </p>
+                    ) : inputs.length === 0 ? (
+                      <p className="crop-info">Idle</p>
+                    ) : (
+                      inputs.map((product) => (
+                        <button
+                          key={product.input}
+                          className="action-button small"
+                          onClick={() =>
+                            loadMachine(machine.plot, product.input)
+                          }
+                        >
+                          {product.input} → {product.output}
+                        </button>
+                      ))
+                    )}
+                  </div>
+                );
+              })}
+            </div>
+            {gameInfo.machines.some((m) => m.processing?.ready) && (
+              <button
+                className="action-button"
+                onClick={collectMachineProducts}
+              >
+                🧺 Collect products
+              </button>
+            )}
+            <div className="crops-grid">
+              {recipes.map((recipe) => (
+                <div key={recipe.id} className="crop-card">
+                  <span className="crop-name">
+                    {recipe.icon} {recipe.name}
+                  </span>
+                  <p className="crop-info">
+                    {recipe.ingredients
+                      .map(
+                        ([item, count]) =>
+                          `${item} ${gameInfo.items[item] ?? 0}/${count}`,
+                      )
+                      .join(", ")}
+                  </p>
+                  <p className="crop-info">{recipe.days} day(s)</p>
+                  <button
+                    className="action-button small"
+                    onClick={() => craft(recipe.id)}
+                    disabled={
+                      gameInfo.farming_level < (recipe.level ?? 0) ||
+                      recipe.ingredients.some(
+                        ([item, count]) => (gameInfo.items[item] ?? 0) < count,
+                      )
+                    }
+                  >
+                    {gameInfo.farming_level < (recipe.level ?? 0)
+                      ? `🔒 Level ${recipe.level}`
+                      : "Craft"}
+                  </button>
+                </div>
+              ))}
+            </div>
+          </div>
+
+          {/* Animals */}
+          <div className="section">
+            <h2>🐄 Animals</h2>
+            <div className="crops-grid">
+              {gameInfo.animals.map((animal) => (
+                <div key={animal.id} className="crop-card">
+                  <span className="crop-name">
+                    {ANIMAL_ICONS[animal.kind]} {animal.name}
+                  </span>
+                  <p className="crop-info">Happiness: {animal.happiness}%</p>
+                  <p className="crop-info">
+                    {animal.fed_today ? "✓ Fed" : "Hungry"}
+                  </p>
+                </div>
+              ))}
+            </div>
+            <p className="crop-info">
+              Coops: {gameInfo.buildings.filter((b) => b === "Coop").length} ·
+              Barns: {gameInfo.buildings.filter((b) => b === "Barn").length}
+            </p>
+            <button
+              className="action-button"
+              onClick={feedAnimals}
+              disabled={gameInfo.animals.every((animal) => animal.fed_today)}
+            >
+              🌾 Feed animals
+            </button>
+            <div className="crops-grid">
+              <button
+                className="action-button small"
+                onClick={() => build("Coop")}
+                disabled={!gameInfo.shop_open}
+              >
+                🛖 Build Coop (1500)
+              </button>
+              <button
+                className="action-button small"
+                onClick={() => build("Barn")}
+                disabled={!gameInfo.shop_open}
+              >
+                🏚️ Build Barn (3000)
+              </button>
+              <button
+                className="action-button small"
+                onClick={() =>
+                  buyAnimal("Chicken", window.prompt("Name your chicken") ?? "")
+                }
+                disabled={!gameInfo.shop_open}
+              >
+                🐔 Buy Chicken (400)
+              </button>
+              <button
+                className="action-button small"
+                onClick={() =>
+                  buyAnimal("Cow", window.prompt("Name your cow") ?? "")
+                }
+                disabled={!gameInfo.shop_open}
+              >
+                🐄 Buy Cow (1200)
+              </button>
+            </div>
+          </div>
+
+          {/* Trading through a relay node */}
+          <div className="section">
+            <h2>🤝 Trading</h2>
+            {relayEndpointId ? (
+              <p className="crop-info">
+                📋 Your Endpoint ID: <code>{relayEndpointId}</code>
+              </p>
+            ) : (
+              <div className="crops-grid">
+                <input
+                  className="name-input"
+                  value={relayUrl}
+                  onChange={(e) => setRelayUrl(e.target.value)}
+                />
+                <button
+                  className="action-button small"
+                  onClick={() => connectRelay(relayUrl)}
+                >
+                  🔗 Connect to relay
+                </button>
+              </div>
+            )}
+            {relayEndpointId && (
+              <div className="crops-grid">
+                <input
+                  className="name-input"
+                  placeholder="Their Endpoint ID"
+                  value={peerId}
+                  onChange={(e) => setPeerId(e.target.value)}
+                />
+                <select
+                  value={tradeGoods}
+                  onChange={(e) => setTradeGoods(e.target.value)}
+                >
+                  <option value="coins">🪙 Coins</option>
+                  {Object.entries(gameInfo.items).map(([name, count]) => (
+                    <option key={name} value={`item:${name}`}>
+                      {name} (x{count})
+                    </option>
+                  ))}
+                  {gameInfo.inventory.map((crop, index) => (
+                    <option key={crop.id} value={`crop:${index}`}>
+                      {crop.icon} {crop.name} seed
+                    </option>
+                  ))}
+                </select>
+                {!tradeGoods.startsWith("crop:") && (
+                  <input
+                    type="number"
+                    min={1}
+                    value={tradeAmount}
+                    onChange={(e) => setTradeAmount(Number(e.target.value))}
+                  />
+                )}
+                <button
+                  className="action-button small"
+                  onClick={handleSendTrade}
+                  disabled={!peerId.trim()}
+                >
+                  📦 Send
+                </button>
+              </div>
+            )}
+            {tradeLog.map((entry, index) => (
+              <p key={index} className="crop-info">
+                {entry}
+              </p>
+            ))}
+          </div>
+
+          {/* Save slots and backups kept in the browser */}
+          <div className="section">
+            <h2>💾 Saves</h2>
+            <div className="crops-grid">
+              {slots.map((name) => (
+                <button
+                  key={name}
+                  className="action-button small"
+                  onClick={() => switchSlot(name)}
+                  disabled={name === slot}
+                >
+                  {name === slot ? `▶ ${name}` : name}
+                </button>
+              ))}
+              <input
+                className="name-input"
+                placeholder="New slot name"
+                value={newSlot}
+                onChange={(e) => setNewSlot(e.target.value)}
+              />
+              <button
+                className="action-button small"
+                onClick={() => switchSlot(newSlot.trim())}
+                disabled={!newSlot.trim()}
+              >
+                🌱 New farm
+              </button>
+            </div>
+            {backups.length > 0 && (
+              <div className="crops-grid">
+                {backups.map((day, index) => (
+                  <button
+                    key={index}
+                    className="action-button small"
+                    onClick={() => restoreBackup(index + 1)}
+                    disabled={day === null}
+                  >
+                    {day === null
+                      ? `❌ Backup ${index + 1} unreadable`
+                      : `🗄️ Restore day ${day}`}
+                  </button>
+                ))}
+              </div>
+            )}
+          </div>
         </div>
 
         {/* Actions Panel */}
@@ -263,6 +768,14 @@ function App() {
             🌾 Harvest
           </button>
 
+          <button
+            className="action-button"
+            onClick={gatherCrops}
+            disabled={!gameInfo.fields.some((c) => c.ready_harvest)}
+          >
+            🧺 Gather
+          </button>
+
           <button className="action-button" onClick={sleep}>
             💤 Sleep
           </button>
@@ -333,6 +846,28 @@ function App() {
               )}
             </div>
 
+            <h2>🧪 Supplies</h2>
+            <div className="seeds-grid">
+              {shopItems.map((item) => (
+                <div key={item.name} className="seed-card">
+                  <h3>{item.name}</h3>
+                  <p className="seed-info">
+                    Owned: {gameInfo.items[item.name] ?? 0}
+                  </p>
+                  <div className="seed-purchase">
+                    <span className="seed-cost">💰 ${item.cost}</span>
+                    <button
+                      className="action-button small"
+                      onClick={() => buyItem(item.name)}
+                      disabled={gameInfo.player_money < item.cost}
+                    >
+                      Buy
+                    </button>
+                  </div>
+                </div>
+              ))}
+            </div>
+
             <button
               className="action-button secondary"
               onClick={() => setShowMarket(false)}
@@ -1,82 +1,43 @@
-// TypeScript types matching the Rust game engine types
-
-export enum Season {
-  Spring = "Spring",
-  Summer = "Summer",
-  Autumn = "Autumn",
-  Winter = "Winter",
-}
-
-export interface Crop {
-  id: string;
-  name: string;
-  growth_days: number;
-  sell_price: number;
-  watered_days: number[];
-  ready_harvest: boolean;
-  energy_cost: number;
-  seasons: Season[];
-  icon: string;
-}
-
-export interface GameInfo {
-  day: number;
-  player_name: string;
-  player_money: number;
-  player_energy: number;
-  max_energy: number;
-  current_season: Season;
-  year: number;
-  day_in_season: number;
-  inventory: Crop[];
-  fields: Crop[];
-}
-
-export interface SeasonChangeEvent {
-  old_season: Season;
-  new_season: Season;
-  day: number;
-  crops_died: string[];
-}
-
-export interface SeedInfo {
-  name: string;
-  cost: number;
-  growth_days: number;
-  sell_price: number;
-  seasons: Season[];
-  icon: string;
-}
-
-export type GameEvent =
-  | {
-      DayAdvanced: { new_day: number; season_change: SeasonChangeEvent | null };
-    }
-  | {
-      Slept: {
-        old_day: number;
-        new_day: number;
-        season_change: SeasonChangeEvent | null;
-      };
-    }
-  | { CropPlanted: { crop_name: string; remaining_energy: number } }
-  | { CropsWatered: { remaining_energy: number } }
-  | { CropsHarvested: { earnings: number; total_money: number } }
-  | { EnergyRestored: null }
-  | {
-      SeedPurchased: {
-        seed_name: string;
-        cost: number;
-        remaining_money: number;
-      };
-    };
-
-export type GameResult = { Success: GameEvent } | { Error: string };
-
-export type GameCommand =
-  | { Sleep: null }
-  | { PlantCrop: { crop_index: number } }
-  | { WaterCrops: null }
-  | { HarvestCrops: null }
-  | { AdvanceDay: null }
-  | { BuySeed: { seed_name: string } };
+// Game types are generated from the Rust engine by `npm run wasm:build`, so
+// they always match what the WASM API returns
+
+export type {
+  Animal,
+  AnimalKind,
+  BuildingKind,
+  Calendar,
+  CraftingJob,
+  Crop,
+  CropFamily,
+  CropQuality,
+  Festival,
+  GameCommand,
+  GameEvent,
+  GameInfo,
+  GameResult,
+  Machine,
+  MachineKind,
+  MachineProduct,
+  Plot,
+  Processing,
+  Recipe,
+  Season,
+  SeasonChangeEvent,
+  SeasonWarning,
+  SeedInfo,
+  ShopItem,
+  Sprinkler,
+  SprinklerKind,
+  TimeMode,
+  TradeItem,
+  TradeItemType,
+} from "./wasm/p2p_harvest_game";
+
+import type { TradeItem } from "./wasm/p2p_harvest_game";
+
+/** Messages from a `p2p-harvest-game relay` node */
+export type RelayMessage =
+  | { Node: { endpoint_id: string } }
+  | { Receive: { id: number; trade_item: TradeItem } }
+  | { Trade: string }
+  | { TradeSettled: { id: number; returned: boolean } };
@@ -1,31 +1,39 @@
-import { useEffect, useState, useCallback } from "react";
-import type { GameInfo, GameResult } from "./types";
-
-interface WasmGameEngine {
-  toJson(): string;
-  getInfo(): string;
-  sleep(): string;
-  plantCrop(cropIndex: number): string;
-  waterCrops(): string;
-  harvestCrops(): string;
-  advanceDay(): string;
-  getDay(): number;
-  getCurrentSeason(): string;
-  buySeed(seedName: string): string;
-  getAvailableSeeds(): string;
+import { useEffect, useState, useCallback, useRef } from "react";
+import type {
+  AnimalKind,
+  BuildingKind,
+  GameInfo,
+  GameResult,
+  MachineProduct,
+  Recipe,
+  RelayMessage,
+  SeedInfo,
+  ShopItem,
+  TradeItem,
+} from "./types";
+import type { WasmGameEngine } from "./wasm/p2p_harvest_game";
+
+type WasmModule = typeof import("./wasm/p2p_harvest_game.js");
+
+export const DEFAULT_SLOT = "default";
+// How often the engine checks whether a real-time day has passed
+const TICK_INTERVAL_MS = 10_000;
+// Remembers the last slot played, so reloading the page stays on that farm
+const SLOT_KEY = "harvest-current-slot";
+// Goods sent through the relay, kept until it says whether the peer took them
+const PENDING_TRADES_KEY = "harvest-pending-trades";
+
+type PendingTrades = Record<string, { slot: string; trade_item: TradeItem }>;
+
+function loadPendingTrades(): PendingTrades {
+  return JSON.parse(localStorage.getItem(PENDING_TRADES_KEY) ?? "{}");
 }
 
-interface WasmGameEngineConstructor {
-  new (playerName: string): WasmGameEngine;
-  fromJson(json: string): WasmGameEngine;
+function savePendingTrades(trades: PendingTrades) {
+  localStorage.setItem(PENDING_TRADES_KEY, JSON.stringify(trades));
 }
 
-interface WasmModule {
-  WasmGameEngine: WasmGameEngineConstructor;
-  default(): Promise<void>;
-}
-
-const STORAGE_KEY = "harvest-game-state";
+export const DEFAULT_RELAY_URL = "ws://localhost:8081/api/relay";
 
 export function useGame() {
   const [gameEngine, setGameEngine] = useState<WasmGameEngine | null>(null);
@@ -35,12 +43,30 @@ export function useGame() {
   const [message, setMessage] = useState<string | null>(null);
   const [showNameInput, setShowNameInput] = useState(false);
   const [playerName, setPlayerName] = useState("");
+  const [relayEndpointId, setRelayEndpointId] = useState<string | null>(null);
+  const [tradeLog, setTradeLog] = useState<string[]>([]);
+  const [slot, setSlot] = useState(DEFAULT_SLOT);
+  const [slots, setSlots] = useState<string[]>([]);
+  const [backups, setBackups] = useState<(number | null)[]>([]);
+  const wasm = useRef<WasmModule | null>(null);
+  const relay = useRef<WebSocket | null>(null);
+  // The relay's message handler outlives renders, so it reads the engine here
+  const engineRef = useRef<WasmGameEngine | null>(null);
+  const slotRef = useRef(DEFAULT_SLOT);
+
+  useEffect(() => {
+    engineRef.current = gameEngine;
+  }, [gameEngine]);
+
+  useEffect(() => {
+    slotRef.current = slot;
+  }, [slot]);
+
+  useEffect(() => () => relay.current?.close(), []);
 
   const updateGameInfo = useCallback((engine: WasmGameEngine) => {
     try {
-      const infoJson = engine.getInfo();
-      const info: GameInfo = JSON.parse(infoJson);
-      setGameInfo(info);
+      setGameInfo(engine.getInfo());
     } catch (e) {
       console.error("Failed to get game info:", e);
       setError("Failed to update game state");
@@ -53,37 +79,35 @@ export function useGame() {
 
     async function initWasm() {
       try {
-        const wasmModule = (await import(
-          "./wasm/p2p_harvest_game.js"
-        )) as unknown as WasmModule;
+        const wasmModule = await import("./wasm/p2p_harvest_game.js");
 
         // Initialize WASM - this is required before using any exports
         await wasmModule.default();
 
         if (!mounted) return;
+        wasm.current = wasmModule;
 
-        // Try to load saved game state
-        const savedState = localStorage.getItem(STORAGE_KEY);
-        let engine: WasmGameEngine;
+        // Try to load the saved game, which then autosaves after every action
+        const savedSlot = localStorage.getItem(SLOT_KEY) ?? DEFAULT_SLOT;
+        setSlot(savedSlot);
 
-        if (savedState) {
-          try {
-            engine = wasmModule.WasmGameEngine.fromJson(savedState);
+        try {
+          const engine = wasmModule.WasmGameEngine.loadSlot(savedSlot);
+          if (engine) {
             setMessage("Game loaded from save!");
             setGameEngine(engine);
             updateGameInfo(engine);
-            setLoading(false);
-          } catch (e) {
-            console.error("Failed to load saved game:", e);
-            // Save corrupted - ask for new name
+          } else {
+            // No saved game - ask for player name
             setShowNameInput(true);
-            setLoading(false);
           }
-        } else {
-          // No saved game - ask for player name
+        } catch (e) {
+          console.error("Failed to load saved game:", e);
+          // Save corrupted - ask for new name, keeping its backups
+          setError(`Failed to load saved game: ${e}`);
           setShowNameInput(true);
-          setLoading(false);
         }
+        setLoading(false);
       } catch (err) {
         console.error("Failed to initialize WASM:", err);
         setError("Failed to load game. Please refresh the page.");
@@ -98,25 +122,47 @@ export function useGame() {
     };
   }, [updateGameInfo]);
 
-  // Save game state whenever it changes
   useEffect(() => {
-    if (gameEngine) {
+    localStorage.setItem(SLOT_KEY, slot);
+  }, [slot]);
+
+  // Keep the save slots and backups listed in step with the game
+  useEffect(() => {
+    if (!wasm.current || !gameInfo) return;
+    try {
+      setSlots(wasm.current.WasmGameEngine.listSlots());
+      setBackups(wasm.current.WasmGameEngine.listBackups(slot));
+    } catch (e) {
+      console.error("Failed to list saves:", e);
+    }
+  }, [gameInfo, slot]);
+
+  // Let real time pass, so days advance on their own in real-time mode
+  useEffect(() => {
+    if (!gameEngine) return;
+
+    const timer = setInterval(() => {
       try {
-        const state = gameEngine.toJson();
-        localStorage.setItem(STORAGE_KEY, state);
+        const events = gameEngine.tick();
+        if (events.length > 0) {
+          setMessage(`🌅 A new day has begun: day ${gameEngine.getDay()}`);
+        }
+        // Refresh even without a new day, to keep the clock moving
+        updateGameInfo(gameEngine);
       } catch (e) {
-        console.error("Failed to save game:", e);
+        console.error("Failed to advance time:", e);
       }
-    }
-  }, [gameInfo, gameEngine]);
+    }, TICK_INTERVAL_MS);
+
+    return () => clearInterval(timer);
+  }, [gameEngine, updateGameInfo]);
 
   const executeAction = useCallback(
-    (action: () => string, successMsg: string) => {
+    (action: () => GameResult, successMsg: string) => {
       if (!gameEngine) return;
 
       try {
-        const resultJson = action();
-        const result: GameResult = JSON.parse(resultJson);
+        const result = action();
 
         if ("Success" in result) {
           setMessage(successMsg);
@@ -160,6 +206,108 @@ export function useGame() {
     executeAction(() => gameEngine!.harvestCrops(), "🌾 Harvest complete!");
   }, [gameEngine, executeAction]);
 
+  const gatherCrops = useCallback(() => {
+    executeAction(() => gameEngine!.gatherCrops(), "🧺 Harvest gathered!");
+  }, [gameEngine, executeAction]);
+
+  const sellItem = useCallback(
+    (itemName: string, quantity: number) => {
+      executeAction(
+        () => gameEngine!.sellItem(itemName, quantity),
+        `🪙 Sold ${quantity} ${itemName}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const craft = useCallback(
+    (recipeId: string) => {
+      executeAction(() => gameEngine!.craft(recipeId), "🔨 Crafting started!");
+    },
+    [gameEngine, executeAction],
+  );
+
+  const getRecipes = useCallback((): Recipe[] => {
+    if (!gameEngine) return [];
+    try {
+      return gameEngine.getRecipes();
+    } catch (e) {
+      console.error("Failed to get recipes:", e);
+      return [];
+    }
+  }, [gameEngine]);
+
+  const placeMachine = useCallback(
+    (plot: number, itemName: string) => {
+      executeAction(
+        () => gameEngine!.placeMachine(plot, itemName),
+        `🏗️ Placed ${itemName} on plot ${plot + 1}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const removeMachine = useCallback(
+    (plot: number) => {
+      executeAction(
+        () => gameEngine!.removeMachine(plot),
+        `🎒 Picked up the machine from plot ${plot + 1}`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const loadMachine = useCallback(
+    (plot: number, itemName: string) => {
+      executeAction(
+        () => gameEngine!.loadMachine(plot, itemName),
+        `⚙️ Loaded ${itemName}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const collectMachineProducts = useCallback(() => {
+    executeAction(
+      () => gameEngine!.collectMachineProducts(),
+      "🧺 Machine products collected!",
+    );
+  }, [gameEngine, executeAction]);
+
+  const build = useCallback(
+    (building: BuildingKind) => {
+      executeAction(
+        () => gameEngine!.build(building),
+        `🔨 Built a ${building}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const buyAnimal = useCallback(
+    (kind: AnimalKind, name: string) => {
+      executeAction(
+        () => gameEngine!.buyAnimal(kind, name),
+        `🐣 Bought a ${kind}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const feedAnimals = useCallback(() => {
+    executeAction(() => gameEngine!.feedAnimals(), "🌾 Animals fed!");
+  }, [gameEngine, executeAction]);
+
+  const getMachineProducts = useCallback((): MachineProduct[] => {
+    if (!gameEngine) return [];
+    try {
+      return gameEngine.getMachineProducts();
+    } catch (e) {
+      console.error("Failed to get machine products:", e);
+      return [];
+    }
+  }, [gameEngine]);
+
   const advanceDay = useCallback(() => {
     executeAction(() => gameEngine!.advanceDay(), "🌅 Day advanced!");
   }, [gameEngine, executeAction]);
@@ -174,11 +322,60 @@ export function useGame() {
     [gameEngine, executeAction],
   );
 
-  const getAvailableSeeds = useCallback(() => {
+  const buyItem = useCallback(
+    (itemName: string) => {
+      executeAction(
+        () => gameEngine!.buyItem(itemName),
+        `🏪 Purchased ${itemName}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const applyFertilizer = useCallback(
+    (plot: number, itemName: string) => {
+      executeAction(
+        () => gameEngine!.applyFertilizer(plot, itemName),
+        `🧪 Fertilized plot ${plot + 1}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const placeSprinkler = useCallback(
+    (plot: number, itemName: string) => {
+      executeAction(
+        () => gameEngine!.placeSprinkler(plot, itemName),
+        `💦 Placed ${itemName} on plot ${plot + 1}!`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const removeSprinkler = useCallback(
+    (plot: number) => {
+      executeAction(
+        () => gameEngine!.removeSprinkler(plot),
+        `🎒 Picked up the sprinkler from plot ${plot + 1}`,
+      );
+    },
+    [gameEngine, executeAction],
+  );
+
+  const getShopItems = useCallback((): ShopItem[] => {
+    if (!gameEngine) return [];
+    try {
+      return gameEngine.getShopItems();
+    } catch (e) {
+      console.error("Failed to get shop items:", e);
+      return [];
+    }
+  }, [gameEngine]);
+
+  const getAvailableSeeds = useCallback((): SeedInfo[] => {
     if (!gameEngine) return [];
     try {
-      const seedsJson = gameEngine.getAvailableSeeds();
-      return JSON.parse(seedsJson);
+      return gameEngine.getAvailableSeeds();
     } catch (e) {
       console.error("Failed to get available seeds:", e);
       return [];
@@ -188,11 +385,10 @@ export function useGame() {
   const startNewGame = useCallback(
     async (name: string) => {
       try {
-        const wasmModule = (await import(
-          "./wasm/p2p_harvest_game.js"
-        )) as unknown as WasmModule;
+        const wasmModule = await import("./wasm/p2p_harvest_game.js");
         await wasmModule.default();
         const newEngine = new wasmModule.WasmGameEngine(name);
+        newEngine.setAutosave(slot);
         setGameEngine(newEngine);
         updateGameInfo(newEngine);
         setShowNameInput(false);
@@ -204,7 +400,7 @@ export function useGame() {
         setError("Failed to start new game");
       }
     },
-    [updateGameInfo],
+    [slot, updateGameInfo],
   );
 
   const handleNameSubmit = useCallback(
@@ -220,21 +416,222 @@ export function useGame() {
   );
 
   const resetGame = useCallback(() => {
-    if (!gameEngine) return;
+    if (!gameEngine || !wasm.current) return;
 
     const confirmed = window.confirm(
-      "Are you sure you want to reset your game? All progress will be lost!",
+      "Are you sure you want to reset your game? Only its backups will be kept!",
     );
     if (!confirmed) return;
 
-    localStorage.removeItem(STORAGE_KEY);
+    try {
+      wasm.current.WasmGameEngine.deleteSlot(slot);
+    } catch (e) {
+      console.error("Failed to delete save:", e);
+      setError(`Failed to delete save: ${e}`);
+      return;
+    }
     setGameEngine(null);
     setGameInfo(null);
     setShowNameInput(true);
     setPlayerName("");
     setMessage("Game reset! Enter your name to start fresh.");
+  }, [gameEngine, slot]);
+
+  // Play the farm saved in a slot, or start a new one there
+  const switchSlot = useCallback(
+    (name: string) => {
+      if (!wasm.current) return;
+
+      try {
+        const engine = wasm.current.WasmGameEngine.loadSlot(name);
+        setSlot(name);
+        setGameEngine(engine ?? null);
+        if (engine) {
+          updateGameInfo(engine);
+          setMessage(`💾 Playing save slot ${name}`);
+        } else {
+          setGameInfo(null);
+          setShowNameInput(true);
+        }
+        setError(null);
+      } catch (e) {
+        console.error("Failed to load save slot:", e);
+        setError(String(e));
+      }
+    },
+    [updateGameInfo],
+  );
+
+  const restoreBackup = useCallback(
+    (backup: number) => {
+      if (!wasm.current) return;
+
+      try {
+        const engine = wasm.current.WasmGameEngine.restoreBackup(slot, backup);
+        setGameEngine(engine);
+        updateGameInfo(engine);
+        setMessage(`🗄️ Restored day ${engine.getDay()}`);
+        setError(null);
+      } catch (e) {
+        console.error("Failed to restore backup:", e);
+        setError(String(e));
+      }
+    },
+    [slot, updateGameInfo],
+  );
+
+  const exportSave = useCallback(() => {
+    if (!gameEngine) return;
+
+    try {
+      const bundle = gameEngine.exportSave();
+      const blob = new Blob([bundle], { type: "application/json" });
+      const url = URL.createObjectURL(blob);
+      const link = document.createElement("a");
+      link.href = url;
+      link.download = "harvest-game-save.json";
+      link.click();
+      URL.revokeObjectURL(url);
+      setMessage("📦 Save exported!");
+    } catch (e) {
+      console.error("Failed to export save:", e);
+      setError("Failed to export save");
+    }
   }, [gameEngine]);
 
+  const importSave = useCallback(
+    async (bundleJson: string) => {
+      try {
+        const wasmModule = await import("./wasm/p2p_harvest_game.js");
+        await wasmModule.default();
+        const engine = wasmModule.WasmGameEngine.importSave(bundleJson);
+        engine.setAutosave(slot);
+        setGameEngine(engine);
+        updateGameInfo(engine);
+        setShowNameInput(false);
+        setMessage("📂 Save imported!");
+        setError(null);
+      } catch (e) {
+        console.error("Failed to import save:", e);
+        setError(`Failed to import save: ${e}`);
+      }
+    },
+    [slot, updateGameInfo],
+  );
+
+  // Settle our side of a trade the relay is carrying, answering with any error
+  const settleTrade = useCallback(
+    (socket: WebSocket, id: number, settle: () => void) => {
+      let error: string | null = null;
+      try {
+        settle();
+      } catch (e) {
+        error = String(e);
+      }
+      socket.send(JSON.stringify({ Answer: { id, error } }));
+      if (engineRef.current) updateGameInfo(engineRef.current);
+    },
+    [updateGameInfo],
+  );
+
+  // Finish a trade we sent, putting the goods back if the peer didn't take them.
+  // The relay repeats this until we confirm, so each trade is only settled once.
+  const finishTrade = useCallback(
+    (socket: WebSocket, id: number, returned: boolean) => {
+      const pending = loadPendingTrades();
+      const trade = pending[id];
+
+      if (trade && returned) {
+        try {
+          if (trade.slot === slotRef.current && engineRef.current) {
+            engineRef.current.receiveTrade(trade.trade_item);
+            updateGameInfo(engineRef.current);
+          } else {
+            // The trade was sent from another farm, so return the goods there
+            const farm = wasm.current?.WasmGameEngine.loadSlot(trade.slot);
+            if (!farm) throw new Error(`No farm in save slot ${trade.slot}`);
+            farm.receiveTrade(trade.trade_item);
+            farm.free();
+          }
+        } catch (e) {
+          // Leave it unconfirmed, so it is tried again next time we connect
+          setError(`Couldn't take back a returned trade: ${e}`);
+          return;
+        }
+      }
+
+      delete pending[id];
+      savePendingTrades(pending);
+      socket.send(JSON.stringify({ Settled: { id } }));
+    },
+    [updateGameInfo],
+  );
+
+  const connectRelay = useCallback(
+    (url: string) => {
+      relay.current?.close();
+
+      const socket = new WebSocket(url);
+      relay.current = socket;
+      socket.onmessage = (event) => {
+        const message: RelayMessage = JSON.parse(event.data);
+        const engine = engineRef.current;
+
+        if ("Node" in message) {
+          setRelayEndpointId(message.Node.endpoint_id);
+        } else if ("Trade" in message) {
+          setTradeLog((log) => [message.Trade, ...log].slice(0, 20));
+        } else if ("Receive" in message) {
+          const { id, trade_item } = message.Receive;
+          settleTrade(socket, id, () => engine!.receiveTrade(trade_item));
+        } else if ("TradeSettled" in message) {
+          const { id, returned } = message.TradeSettled;
+          finishTrade(socket, id, returned);
+        }
+      };
+      socket.onclose = () => {
+        if (relay.current === socket) {
+          relay.current = null;
+          setRelayEndpointId(null);
+        }
+      };
+      socket.onerror = () =>
+        setError(`Couldn't reach the trade relay at ${url}`);
+    },
+    [settleTrade, finishTrade],
+  );
+
+  const sendTrade = useCallback(
+    (endpointId: string, tradeItem: TradeItem) => {
+      const socket = relay.current;
+      if (!gameEngine || !socket || socket.readyState !== WebSocket.OPEN)
+        return;
+
+      // Take the goods out now so they can't be spent while the trade is under
+      // way, keeping them aside until the relay settles the trade
+      try {
+        gameEngine.giveTrade(tradeItem);
+      } catch (e) {
+        setError(String(e));
+        return;
+      }
+      updateGameInfo(gameEngine);
+
+      const id = Date.now();
+      savePendingTrades({
+        ...loadPendingTrades(),
+        [id]: { slot, trade_item: tradeItem },
+      });
+
+      socket.send(
+        JSON.stringify({
+          SendTrade: { id, endpoint_id: endpointId, trade_item: tradeItem },
+        }),
+      );
+    },
+    [gameEngine, slot, updateGameInfo],
+  );
+
   const clearMessage = useCallback(() => {
     setMessage(null);
     setError(null);
@@ -249,11 +646,39 @@ export function useGame() {
     plantCrop,
     waterCrops,
     harvestCrops,
+    gatherCrops,
+    sellItem,
+    craft,
+    getRecipes,
+    placeMachine,
+    removeMachine,
+    loadMachine,
+    collectMachineProducts,
+    getMachineProducts,
+    build,
+    buyAnimal,
+    feedAnimals,
     advanceDay,
     buySeed,
+    buyItem,
+    applyFertilizer,
+    placeSprinkler,
+    removeSprinkler,
     getAvailableSeeds,
+    getShopItems,
     resetGame,
+    exportSave,
+    importSave,
+    slot,
+    slots,
+    backups,
+    switchSlot,
+    restoreBackup,
     clearMessage,
+    relayEndpointId,
+    tradeLog,
+    connectRelay,
+    sendTrade,
     showNameInput,
     playerName,
     setPlayerName,
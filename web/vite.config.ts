@@ -7,6 +7,10 @@ export default defineConfig({
   plugins: [react()],
   server: {
     port: 3000,
+    // Forward API calls to a native node started with `p2p-harvest-game serve`
+    proxy: {
+      "/api": { target: "http://localhost:8080", ws: true },
+    },
     fs: {
       allow: [".."],
     },